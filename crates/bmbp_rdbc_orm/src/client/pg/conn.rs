@@ -1,6 +1,7 @@
 use crate::conn::RdbcTransaction;
 use crate::ds::RdbcDbConfig;
 use crate::orm::RdbcOrmExecutor;
+use bmbp_rdbc_sql::RdbcSqlDialect;
 use bmbp_rdbc_type::{RdbcErrKind, RdbcError, RdbcPage, RdbcRow, RdbcValue};
 use chrono::Duration;
 use serde::Serialize;
@@ -64,6 +65,7 @@ impl RdbcPgConnection {
             ));
         }
         let pg_trans = RdbcPgTransaction {
+            db_config: self.db_config.clone(),
             trans: Some(trans_rs.unwrap()),
             done: false,
         };
@@ -72,6 +74,9 @@ impl RdbcPgConnection {
 }
 
 impl RdbcOrmExecutor for RdbcPgConnection {
+    fn dialect(&self) -> Box<dyn RdbcSqlDialect> {
+        self.db_config.dialect()
+    }
     async fn query_page(
         &self,
         _page_num: usize,
@@ -166,6 +171,7 @@ impl RdbcOrmExecutor for RdbcPgConnection {
 }
 
 pub struct RdbcPgTransaction<'a> {
+    db_config: Arc<RdbcDbConfig>,
     trans: Option<Transaction<'a>>,
     done: bool,
 }
@@ -205,6 +211,9 @@ impl<'a> RdbcPgTransaction<'a> {
 }
 
 impl RdbcOrmExecutor for RdbcPgTransaction<'_> {
+    fn dialect(&self) -> Box<dyn RdbcSqlDialect> {
+        self.db_config.dialect()
+    }
     async fn query_page(
         &self,
         _page_num: usize,
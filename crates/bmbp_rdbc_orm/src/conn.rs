@@ -2,6 +2,7 @@ use crate::client::{RdbcPgConnection, RdbcPgTransaction};
 use crate::ds::{RdbcDbConfig, RdbcDbType};
 use crate::orm::RdbcOrmExecutor;
 use crate::pool::RdbcPool;
+use bmbp_rdbc_sql::{RdbcReturningSql, RdbcSqlDialect};
 use bmbp_rdbc_type::{RdbcErrKind, RdbcError, RdbcPage, RdbcRow, RdbcValue};
 use serde::Serialize;
 use std::fmt::Debug;
@@ -57,6 +58,9 @@ impl Drop for RdbcPooledConnection {
 }
 
 impl RdbcOrmExecutor for RdbcPooledConnection {
+    fn dialect(&self) -> Box<dyn RdbcSqlDialect> {
+        self.db_config.dialect()
+    }
     async fn query_page(
         &self,
         page_num: usize,
@@ -223,6 +227,22 @@ impl RdbcOrmExecutor for RdbcPooledConnection {
             ))
         }
     }
+
+    /// 连接为自动提交，模拟 RETURNING 的两条语句须在事务中执行
+    async fn execute_returning(
+        &self,
+        returning: RdbcReturningSql,
+    ) -> Result<Vec<RdbcRow>, RdbcError> {
+        match returning {
+            RdbcReturningSql::Returning(execute_sql, params) => {
+                self.query_list(execute_sql, params.as_slice()).await
+            }
+            _ => Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "模拟 RETURNING 需要在事务中执行".to_string(),
+            )),
+        }
+    }
 }
 pub enum RdbcConnection {
     Pg(RdbcPgConnection),
@@ -236,6 +256,11 @@ impl RdbcConnection {
 }
 
 impl RdbcOrmExecutor for RdbcConnection {
+    fn dialect(&self) -> Box<dyn RdbcSqlDialect> {
+        match self {
+            RdbcConnection::Pg(conn) => conn.dialect(),
+        }
+    }
     async fn query_page(
         &self,
         page_num: usize,
@@ -372,6 +397,11 @@ impl<'a> RdbcTransaction<'a> {
     }
 }
 impl RdbcOrmExecutor for RdbcTransaction<'_> {
+    fn dialect(&self) -> Box<dyn RdbcSqlDialect> {
+        match self {
+            RdbcTransaction::Pg(trans) => trans.dialect(),
+        }
+    }
     async fn query_page(&self, page_num: usize, page_size: usize, execute_sql: String, params: &[RdbcValue]) -> Result<RdbcPage<RdbcRow>, RdbcError> {
         match self {
             RdbcTransaction::Pg(trans) => trans.query_page(page_num, page_size, execute_sql, params).await,
@@ -273,6 +273,7 @@ where
         update.set(T::status_key(), "Y");
         Ok(update)
     }
+    /// 未开启 allow_delete_all，无过滤条件时渲染会返回错误
     fn rdbc_delete_all() -> Result<RdbcDeleteBuilder, RdbcError> {
         let mut delete = RdbcDeleteBuilder::new();
         delete.table(T::table_name());
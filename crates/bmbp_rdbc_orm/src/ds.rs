@@ -1,3 +1,6 @@
+use bmbp_rdbc_sql::{
+    RdbcMysqlDialect, RdbcOracleDialect, RdbcPostgresDialect, RdbcSqlDialect, RdbcSqliteDialect,
+};
 use chrono::Duration;
 
 pub enum RdbcDbType {
@@ -15,6 +18,8 @@ pub struct RdbcDbConfig {
     pub database_name: String,
     pub schema: String,
     pub pool_config: Option<RdbcDbPoolConfig>,
+    /// 数据库版本，Oracle 为主版本号，MySQL、SQLite 为 8_000_014 形式，未指定时使用方言默认版本
+    pub server_version: Option<u32>,
 }
 
 impl RdbcDbConfig {
@@ -37,6 +42,29 @@ impl RdbcDbConfig {
             database_name: database_name.to_string(),
             schema: schema.to_string(),
             pool_config,
+            server_version: None,
+        }
+    }
+    pub fn with_server_version(mut self, server_version: u32) -> Self {
+        self.server_version = Some(server_version);
+        self
+    }
+    /// 按数据库类型选择 SQL 方言
+    pub fn dialect(&self) -> Box<dyn RdbcSqlDialect> {
+        match self.database_type {
+            RdbcDbType::Mysql => match self.server_version {
+                Some(version) => Box::new(RdbcMysqlDialect::new(version)),
+                None => Box::new(RdbcMysqlDialect::default()),
+            },
+            RdbcDbType::Postgres => Box::new(RdbcPostgresDialect),
+            RdbcDbType::Sqlite => match self.server_version {
+                Some(version) => Box::new(RdbcSqliteDialect::new(version)),
+                None => Box::new(RdbcSqliteDialect::default()),
+            },
+            RdbcDbType::Oracle => match self.server_version {
+                Some(version) => Box::new(RdbcOracleDialect::new(version)),
+                None => Box::new(RdbcOracleDialect::default()),
+            },
         }
     }
 }
@@ -1,6 +1,9 @@
 use crate::ds::RdbcDbConfig;
 use crate::pool::RdbcPool;
-use bmbp_rdbc_sql::{RdbcDelete, RdbcInsert, RdbcQuery, RdbcQueryRender, RdbcUpdate};
+use bmbp_rdbc_sql::{
+    RdbcDelete, RdbcDeleteRender, RdbcInsert, RdbcInsertRender, RdbcQuery, RdbcQueryRender,
+    RdbcReturningSql, RdbcSqlDialect, RdbcSqlRender, RdbcUpdate, RdbcUpdateRender,
+};
 use bmbp_rdbc_type::{RdbcError, RdbcPage, RdbcRow, RdbcValue};
 use serde::Serialize;
 use std::fmt::Debug;
@@ -9,6 +12,8 @@ pub trait RdbcOrmExecutor
 where
     Self: Sized,
 {
+    /// 当前连接使用的 SQL 方言
+    fn dialect(&self) -> Box<dyn RdbcSqlDialect>;
     async fn query_page(
         &self,
         page_num: usize,
@@ -61,6 +66,46 @@ where
         &self,
         execute_sql_params: &[(&String, &[&RdbcValue])],
     ) -> Result<usize, RdbcError>;
+    /// 执行带返回行的 DML，模拟 RETURNING 时回查语句与 DML 须在同一事务中执行
+    async fn execute_returning(
+        &self,
+        returning: RdbcReturningSql,
+    ) -> Result<Vec<RdbcRow>, RdbcError> {
+        match returning {
+            RdbcReturningSql::Returning(execute_sql, params) => {
+                self.query_list(execute_sql, params.as_slice()).await
+            }
+            RdbcReturningSql::ExecuteThenQuery {
+                execute_sql,
+                execute_params,
+                query_sql,
+                query_params,
+            } => {
+                self.execute(execute_sql, execute_params.as_slice()).await?;
+                self.query_list(query_sql, query_params.as_slice()).await
+            }
+            RdbcReturningSql::QueryThenExecute {
+                query_sql,
+                query_params,
+                execute_sql,
+                execute_params,
+            } => {
+                let rows = self.query_list(query_sql, query_params.as_slice()).await?;
+                self.execute(execute_sql, execute_params.as_slice()).await?;
+                Ok(rows)
+            }
+        }
+    }
+    async fn execute_returning_as<T>(
+        &self,
+        returning: RdbcReturningSql,
+    ) -> Result<Vec<T>, RdbcError>
+    where
+        T: From<RdbcRow> + Debug + Default + Serialize + Clone,
+    {
+        let rows = self.execute_returning(returning).await?;
+        Ok(rows.into_iter().map(T::from).collect())
+    }
 }
 pub struct RdbcOrm {}
 impl RdbcOrm {
@@ -198,8 +243,10 @@ impl RdbcOrm {
     where
         E: RdbcOrmExecutor,
     {
+        let (sql, params) =
+            RdbcQueryRender::new_with_dialect(query.clone(), executor.dialect()).render_sql()?;
         executor
-            .query_page(page_num, page_size, "".to_string(), &[])
+            .query_page(page_num, page_size, sql, params.as_slice())
             .await
     }
     pub async fn query_pag_by_query_as<E, T>(
@@ -212,8 +259,10 @@ impl RdbcOrm {
         E: RdbcOrmExecutor,
         T: From<RdbcRow> + Debug + Default + Serialize + Clone,
     {
+        let (sql, params) =
+            RdbcQueryRender::new_with_dialect(query.clone(), executor.dialect()).render_sql()?;
         executor
-            .query_page_as(page_num, page_size, "".to_string(), &[])
+            .query_page_as(page_num, page_size, sql, params.as_slice())
             .await
     }
     pub async fn query_list_by_query<E>(
@@ -223,7 +272,9 @@ impl RdbcOrm {
     where
         E: RdbcOrmExecutor,
     {
-        executor.query_list("".to_string(), &[]).await
+        let (sql, params) =
+            RdbcQueryRender::new_with_dialect(query.clone(), executor.dialect()).render_sql()?;
+        executor.query_list(sql, params.as_slice()).await
     }
     pub async fn query_list_by_query_as<E, T>(
         executor: &E,
@@ -233,7 +284,9 @@ impl RdbcOrm {
         E: RdbcOrmExecutor,
         T: From<RdbcRow> + Debug + Default + Serialize + Clone,
     {
-        executor.query_list_as("".to_string(), &[]).await
+        let (sql, params) =
+            RdbcQueryRender::new_with_dialect(query.clone(), executor.dialect()).render_sql()?;
+        executor.query_list_as(sql, params.as_slice()).await
     }
     pub async fn query_one_option_by_query<E>(
         executor: &E,
@@ -242,7 +295,9 @@ impl RdbcOrm {
     where
         E: RdbcOrmExecutor,
     {
-        executor.query_one_option("".to_string(), &[]).await
+        let (sql, params) =
+            RdbcQueryRender::new_with_dialect(query.clone(), executor.dialect()).render_sql()?;
+        executor.query_one_option(sql, params.as_slice()).await
     }
     pub async fn query_one_option_by_query_as<E, T>(
         executor: &E,
@@ -252,35 +307,174 @@ impl RdbcOrm {
         E: RdbcOrmExecutor,
         T: From<RdbcRow> + Debug + Default + Serialize + Clone,
     {
-        executor.query_one_option_as("".to_string(), &[]).await
+        let (sql, params) =
+            RdbcQueryRender::new_with_dialect(query.clone(), executor.dialect()).render_sql()?;
+        executor.query_one_option_as(sql, params.as_slice()).await
     }
 
-    pub async fn execute_insert<E>(executor: &E, insert: &RdbcInsert) -> Result<usize, RdbcError> {
-        Ok(0)
+    pub async fn execute_insert<E>(executor: &E, insert: &RdbcInsert) -> Result<usize, RdbcError>
+    where
+        E: RdbcOrmExecutor,
+    {
+        let (sql, params) =
+            RdbcInsertRender::new_with_dialect(insert.clone(), executor.dialect()).render_sql()?;
+        executor.execute(sql, params.as_slice()).await
     }
     pub async fn execute_batch_insert<E>(
         executor: &E,
         insert: &[RdbcInsert],
-    ) -> Result<usize, RdbcError> {
-        Ok(0)
+    ) -> Result<usize, RdbcError>
+    where
+        E: RdbcOrmExecutor,
+    {
+        let mut rendered = vec![];
+        for item in insert {
+            rendered.push(
+                RdbcInsertRender::new_with_dialect(item.clone(), executor.dialect())
+                    .render_sql()?,
+            );
+        }
+        Self::execute_batch_rendered(executor, &rendered).await
     }
-    pub async fn execute_update<E>(executor: &E, insert: &RdbcUpdate) -> Result<usize, RdbcError> {
-        Ok(0)
+    pub async fn execute_update<E>(executor: &E, update: &RdbcUpdate) -> Result<usize, RdbcError>
+    where
+        E: RdbcOrmExecutor,
+    {
+        let (sql, params) =
+            RdbcUpdateRender::new_with_dialect(update.clone(), executor.dialect()).render_sql()?;
+        executor.execute(sql, params.as_slice()).await
     }
     pub async fn execute_batch_update<E>(
         executor: &E,
-        insert: &[RdbcUpdate],
-    ) -> Result<usize, RdbcError> {
-        Ok(0)
+        update: &[RdbcUpdate],
+    ) -> Result<usize, RdbcError>
+    where
+        E: RdbcOrmExecutor,
+    {
+        let mut rendered = vec![];
+        for item in update {
+            rendered.push(
+                RdbcUpdateRender::new_with_dialect(item.clone(), executor.dialect())
+                    .render_sql()?,
+            );
+        }
+        Self::execute_batch_rendered(executor, &rendered).await
     }
-    pub async fn execute_delete<E>(executor: &E, insert: &RdbcDelete) -> Result<usize, RdbcError> {
-        Ok(0)
+    pub async fn execute_delete<E>(executor: &E, delete: &RdbcDelete) -> Result<usize, RdbcError>
+    where
+        E: RdbcOrmExecutor,
+    {
+        let (sql, params) =
+            RdbcDeleteRender::new_with_dialect(delete.clone(), executor.dialect()).render_sql()?;
+        executor.execute(sql, params.as_slice()).await
     }
     pub async fn execute_batch_delete<E>(
         executor: &E,
-        insert: &[RdbcDelete],
-    ) -> Result<usize, RdbcError> {
-        Ok(0)
+        delete: &[RdbcDelete],
+    ) -> Result<usize, RdbcError>
+    where
+        E: RdbcOrmExecutor,
+    {
+        let mut rendered = vec![];
+        for item in delete {
+            rendered.push(
+                RdbcDeleteRender::new_with_dialect(item.clone(), executor.dialect())
+                    .render_sql()?,
+            );
+        }
+        Self::execute_batch_rendered(executor, &rendered).await
+    }
+
+    /// 已渲染的语句交由执行器批量执行，连接池在同一事务中执行，失败整体回滚
+    async fn execute_batch_rendered<E>(
+        executor: &E,
+        rendered: &[(String, Vec<RdbcValue>)],
+    ) -> Result<usize, RdbcError>
+    where
+        E: RdbcOrmExecutor,
+    {
+        let params: Vec<Vec<&RdbcValue>> = rendered
+            .iter()
+            .map(|(_, params)| params.iter().collect())
+            .collect();
+        let execute_sql_params: Vec<(&String, &[&RdbcValue])> = rendered
+            .iter()
+            .zip(params.iter())
+            .map(|((sql, _), params)| (sql, params.as_slice()))
+            .collect();
+        executor.execute_batch_slice(&execute_sql_params).await
+    }
+
+    /// 插入并返回 RETURNING 列，不支持 RETURNING 的数据库按主键回查
+    pub async fn execute_insert_returning<E>(
+        executor: &E,
+        insert: &RdbcInsert,
+    ) -> Result<Vec<RdbcRow>, RdbcError>
+    where
+        E: RdbcOrmExecutor,
+    {
+        let returning = RdbcInsertRender::new_with_dialect(insert.clone(), executor.dialect())
+            .render_returning_sql()?;
+        executor.execute_returning(returning).await
+    }
+    pub async fn execute_insert_returning_as<E, T>(
+        executor: &E,
+        insert: &RdbcInsert,
+    ) -> Result<Vec<T>, RdbcError>
+    where
+        E: RdbcOrmExecutor,
+        T: From<RdbcRow> + Debug + Default + Serialize + Clone,
+    {
+        let returning = RdbcInsertRender::new_with_dialect(insert.clone(), executor.dialect())
+            .render_returning_sql()?;
+        executor.execute_returning_as(returning).await
+    }
+    pub async fn execute_update_returning<E>(
+        executor: &E,
+        update: &RdbcUpdate,
+    ) -> Result<Vec<RdbcRow>, RdbcError>
+    where
+        E: RdbcOrmExecutor,
+    {
+        let returning = RdbcUpdateRender::new_with_dialect(update.clone(), executor.dialect())
+            .render_returning_sql()?;
+        executor.execute_returning(returning).await
+    }
+    pub async fn execute_update_returning_as<E, T>(
+        executor: &E,
+        update: &RdbcUpdate,
+    ) -> Result<Vec<T>, RdbcError>
+    where
+        E: RdbcOrmExecutor,
+        T: From<RdbcRow> + Debug + Default + Serialize + Clone,
+    {
+        let returning = RdbcUpdateRender::new_with_dialect(update.clone(), executor.dialect())
+            .render_returning_sql()?;
+        executor.execute_returning_as(returning).await
+    }
+    /// 删除并返回被删除的行，不支持 RETURNING 的数据库先锁定查询，连接池执行时自动开启事务
+    pub async fn execute_delete_returning<E>(
+        executor: &E,
+        delete: &RdbcDelete,
+    ) -> Result<Vec<RdbcRow>, RdbcError>
+    where
+        E: RdbcOrmExecutor,
+    {
+        let returning = RdbcDeleteRender::new_with_dialect(delete.clone(), executor.dialect())
+            .render_returning_sql()?;
+        executor.execute_returning(returning).await
+    }
+    pub async fn execute_delete_returning_as<E, T>(
+        executor: &E,
+        delete: &RdbcDelete,
+    ) -> Result<Vec<T>, RdbcError>
+    where
+        E: RdbcOrmExecutor,
+        T: From<RdbcRow> + Debug + Default + Serialize + Clone,
+    {
+        let returning = RdbcDeleteRender::new_with_dialect(delete.clone(), executor.dialect())
+            .render_returning_sql()?;
+        executor.execute_returning_as(returning).await
     }
 }
 
@@ -1,7 +1,7 @@
 use crate::conn::RdbcPooledConnection;
 use crate::ds::RdbcDbConfig;
 use crate::orm::RdbcOrmExecutor;
-use bmbp_rdbc_sql::RdbcFilterType;
+use bmbp_rdbc_sql::{RdbcFilterType, RdbcReturningSql, RdbcSqlDialect};
 use bmbp_rdbc_type::{RdbcErrKind, RdbcError, RdbcPage, RdbcRow, RdbcValue};
 use chrono::Duration;
 use serde::Serialize;
@@ -73,6 +73,9 @@ impl RdbcPool {
 }
 
 impl RdbcOrmExecutor for Arc<RdbcPool> {
+    fn dialect(&self) -> Box<dyn RdbcSqlDialect> {
+        self.db_config.dialect()
+    }
     async fn query_page(
         &self,
         page_num: usize,
@@ -152,31 +155,94 @@ impl RdbcOrmExecutor for Arc<RdbcPool> {
         Ok(0usize)
     }
 
+    /// 批量语句在同一事务中执行，任一语句失败整体回滚
     async fn execute_batch(
         &self,
         execute_sql: String,
         params: &[&[RdbcValue]],
     ) -> Result<usize, RdbcError> {
-        self.get_connection()?
-            .execute_batch(execute_sql, params)
-            .await
+        let mut con = self.get_connection()?;
+        let mut trans = con.get_transaction().await?;
+        match trans.execute_batch(execute_sql, params).await {
+            Ok(count) => {
+                trans.commit().await?;
+                Ok(count)
+            }
+            Err(err) => {
+                trans.rollback().await?;
+                Err(err)
+            }
+        }
     }
 
     async fn execute_raw(&self, _execute_sql: String) -> Result<usize, RdbcError> {
         self.get_connection()?.execute_raw(_execute_sql).await
     }
 
-    async fn execute_batch_raw(&self, _execute_sql: &[String]) -> Result<usize, RdbcError> {
-        self.get_connection()?.execute_batch_raw(_execute_sql).await
+    async fn execute_batch_raw(&self, execute_sql: &[String]) -> Result<usize, RdbcError> {
+        let mut con = self.get_connection()?;
+        let mut trans = con.get_transaction().await?;
+        match trans.execute_batch_raw(execute_sql).await {
+            Ok(count) => {
+                trans.commit().await?;
+                Ok(count)
+            }
+            Err(err) => {
+                trans.rollback().await?;
+                Err(err)
+            }
+        }
     }
 
     async fn execute_batch_slice(
         &self,
-        _execute_sql_params: &[(&String, &[&RdbcValue])],
+        execute_sql_params: &[(&String, &[&RdbcValue])],
     ) -> Result<usize, RdbcError> {
-        self.get_connection()?
-            .execute_batch_slice(_execute_sql_params)
-            .await
+        let mut con = self.get_connection()?;
+        let mut trans = con.get_transaction().await?;
+        match trans.execute_batch_slice(execute_sql_params).await {
+            Ok(count) => {
+                trans.commit().await?;
+                Ok(count)
+            }
+            Err(err) => {
+                trans.rollback().await?;
+                Err(err)
+            }
+        }
+    }
+
+    /// 模拟 RETURNING 时回查语句与 DML 在同一事务中执行，保证行锁与自增主键有效
+    async fn execute_returning(
+        &self,
+        returning: RdbcReturningSql,
+    ) -> Result<Vec<RdbcRow>, RdbcError> {
+        let mut con = self.get_connection()?;
+        if let RdbcReturningSql::Returning(_, _) = returning {
+            return con.execute_returning(returning).await;
+        }
+        let mut trans = con.get_transaction().await?;
+        match trans.execute_returning(returning).await {
+            Ok(rows) => {
+                trans.commit().await?;
+                Ok(rows)
+            }
+            Err(err) => {
+                trans.rollback().await?;
+                Err(err)
+            }
+        }
+    }
+
+    async fn execute_returning_as<T>(
+        &self,
+        returning: RdbcReturningSql,
+    ) -> Result<Vec<T>, RdbcError>
+    where
+        T: From<RdbcRow> + Debug + Default + Serialize + Clone,
+    {
+        let rows = self.execute_returning(returning).await?;
+        Ok(rows.into_iter().map(T::from).collect())
     }
 }
 #[cfg(test)]
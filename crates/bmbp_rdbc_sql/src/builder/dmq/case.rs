@@ -0,0 +1,118 @@
+use crate::filter::RdbcWhereFilterBuilder;
+use crate::{
+    RdbcCaseColumn, RdbcCaseWhen, RdbcColumn, RdbcFilterType, RdbcSimpleColumn, RdbcValueColumn,
+    RdbcWhereFilter,
+};
+use bmbp_rdbc_type::{RdbcIdent, RdbcValue};
+
+/// CASE 表达式构建器
+pub struct RdbcCaseBuilder {
+    case: RdbcCaseColumn,
+}
+
+impl RdbcCaseBuilder {
+    /// 条件式 CASE WHEN 条件 THEN ...
+    pub fn new() -> Self {
+        RdbcCaseBuilder {
+            case: RdbcCaseColumn {
+                operand: None,
+                when: vec![],
+                else_: None,
+            },
+        }
+    }
+    /// 简单 CASE column WHEN 值 THEN ...
+    pub fn new_with_column<C>(column: C) -> Self
+    where
+        C: RdbcIdent,
+    {
+        Self::new_with_rdbc_column(RdbcColumn::SimpleColumn(RdbcSimpleColumn {
+            column: column.name(),
+        }))
+    }
+    pub fn new_with_rdbc_column(column: RdbcColumn) -> Self {
+        let mut builder = Self::new();
+        builder.case.operand = Some(Box::new(column));
+        builder
+    }
+    pub fn build(&self) -> RdbcCaseColumn {
+        self.case.clone()
+    }
+
+    /// 新增条件分支，返回的分支上继续添加 WHEN 条件
+    pub fn when_v<T>(&mut self, then: T) -> &mut RdbcCaseWhen
+    where
+        RdbcValue: From<T>,
+    {
+        self.when_column(value_column(RdbcValue::from(then)))
+    }
+    pub fn when_column(&mut self, then: RdbcColumn) -> &mut RdbcCaseWhen {
+        self.case.when.push(RdbcCaseWhen {
+            filter: None,
+            value: None,
+            then,
+        });
+        self.case.when.last_mut().unwrap()
+    }
+    pub fn when_filter(&mut self, filter: RdbcWhereFilter, then: RdbcColumn) -> &mut Self {
+        self.case.when.push(RdbcCaseWhen {
+            filter: Some(filter),
+            value: None,
+            then,
+        });
+        self
+    }
+
+    /// 简单 CASE 的分支，WHEN value THEN then
+    pub fn when_eq_v<V, T>(&mut self, value: V, then: T) -> &mut Self
+    where
+        RdbcValue: From<V>,
+        RdbcValue: From<T>,
+    {
+        self.when_eq_column(
+            value_column(RdbcValue::from(value)),
+            value_column(RdbcValue::from(then)),
+        )
+    }
+    pub fn when_eq_column(&mut self, value: RdbcColumn, then: RdbcColumn) -> &mut Self {
+        self.case.when.push(RdbcCaseWhen {
+            filter: None,
+            value: Some(value),
+            then,
+        });
+        self
+    }
+
+    pub fn else_v<T>(&mut self, value: T) -> &mut Self
+    where
+        RdbcValue: From<T>,
+    {
+        self.else_column(value_column(RdbcValue::from(value)))
+    }
+    pub fn else_column(&mut self, column: RdbcColumn) -> &mut Self {
+        self.case.else_ = Some(Box::new(column));
+        self
+    }
+}
+
+impl Default for RdbcCaseBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RdbcWhereFilterBuilder for RdbcCaseWhen {
+    fn filter_mut(&mut self) -> &mut RdbcWhereFilter {
+        self.filter.get_or_insert(RdbcWhereFilter {
+            type_: RdbcFilterType::And,
+            conditions: vec![],
+        })
+    }
+    fn filter_take(&mut self) -> Option<RdbcWhereFilter> {
+        self.filter.take()
+    }
+}
+
+fn value_column(column: RdbcValue) -> RdbcColumn {
+    RdbcColumn::ValueColumn(RdbcValueColumn { column })
+}
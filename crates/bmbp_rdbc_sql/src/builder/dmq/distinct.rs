@@ -0,0 +1,34 @@
+use crate::{RdbcColumn, RdbcSimpleColumn};
+use bmbp_rdbc_type::RdbcIdent;
+
+/// 查询去重，SELECT DISTINCT 与 SELECT DISTINCT ON (columns)
+pub trait RdbcDistinctBuilder {
+    fn distinct_mut(&mut self) -> &mut bool;
+    fn distinct_on_mut(&mut self) -> &mut Vec<RdbcColumn>;
+    fn distinct(&mut self) -> &mut Self {
+        *self.distinct_mut() = true;
+        self
+    }
+    /// 按列去重，每组保留 ORDER BY 排序后的第一行
+    fn distinct_on<C>(&mut self, column: C) -> &mut Self
+    where
+        C: RdbcIdent,
+    {
+        self.distinct_on_column(RdbcColumn::SimpleColumn(RdbcSimpleColumn {
+            column: column.name(),
+        }))
+    }
+    fn distinct_on_vec<C>(&mut self, columns: Vec<C>) -> &mut Self
+    where
+        C: RdbcIdent,
+    {
+        for column in columns {
+            self.distinct_on(column);
+        }
+        self
+    }
+    fn distinct_on_column(&mut self, column: RdbcColumn) -> &mut Self {
+        self.distinct_on_mut().push(column);
+        self
+    }
+}
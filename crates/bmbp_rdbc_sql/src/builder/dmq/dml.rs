@@ -1,12 +1,50 @@
 use crate::builder::dmq::filter::RdbcWhereFilterBuilder;
 use crate::builder::dmq::limit::{RdbcLimitBuilder, RdbcOffsetBuilder};
 use crate::builder::dmq::order::RdbcOrderBuilder;
+use crate::builder::dmq::returning::RdbcReturningBuilder;
 use crate::builder::dmq::table::{RdbcJoinTableBuilder, RdbcTableBuilder};
-use crate::{RdbcDelete, RdbcFilterType, RdbcInsert, RdbcJoinTable, RdbcOrderColumn,  RdbcTable, RdbcUpdate, RdbcWhereFilter};
+use crate::{
+    RdbcColumn, RdbcConflict, RdbcConflictAction, RdbcConflictColumn, RdbcConflictTarget,
+    RdbcConflictValue, RdbcDelete, RdbcDmlColumn, RdbcDmlValue, RdbcExprColumn, RdbcFilterType,
+    RdbcFunc, RdbcInsert,
+    RdbcJoinTable, RdbcOrderColumn, RdbcQuery, RdbcSimpleColumn, RdbcTable, RdbcUpdate, RdbcWhereFilter,
+};
+use bmbp_rdbc_type::{RdbcIdent, RdbcValue};
 
 pub struct RdbcDeleteBuilder {
     delete: RdbcDelete,
 }
+
+impl Default for RdbcDeleteBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RdbcDeleteBuilder {
+    pub fn new() -> Self {
+        RdbcDeleteBuilder {
+            delete: RdbcDelete {
+                table: vec![],
+                join_table: vec![],
+                where_: None,
+                order_by: vec![],
+                limit: None,
+                offset: None,
+                allow_delete_all: false,
+                returning: vec![],
+            },
+        }
+    }
+    pub fn build(&self) -> RdbcDelete {
+        self.delete.clone()
+    }
+    /// 显式允许无过滤条件删除全表，否则渲染时返回错误
+    pub fn allow_delete_all(&mut self) -> &mut Self {
+        self.delete.allow_delete_all = true;
+        self
+    }
+}
 impl RdbcTableBuilder for RdbcDeleteBuilder {
     fn table_mut(&mut self) -> &mut Vec<RdbcTable> {
         self.delete.table.as_mut()
@@ -24,7 +62,6 @@ impl RdbcWhereFilterBuilder for RdbcDeleteBuilder {
         self.delete.where_.get_or_insert(RdbcWhereFilter{
             type_: RdbcFilterType::And,
             conditions: vec![],
-            distinct: false,
         })
     }
 
@@ -65,10 +102,40 @@ impl RdbcUpdateBuilder {
                   where_: None, 
                   order_by: vec![], 
                   limit: None, 
-                  offset: None
+                  offset: None,
+                  returning: vec![],
                  }
         }
     }
+    pub fn build(&self) -> RdbcUpdate {
+        self.update.clone()
+    }
+    pub fn set<C, V>(&mut self, column: C, value: V) -> &mut Self
+    where
+        C: RdbcIdent,
+        RdbcValue: From<V>,
+    {
+        self.update.column_value.push(RdbcDmlColumn {
+            column: RdbcColumn::SimpleColumn(RdbcSimpleColumn {
+                column: column.name(),
+            }),
+            value: RdbcDmlValue::VALUE(RdbcValue::from(value)),
+        });
+        self
+    }
+    /// SET column = 表达式，如 data_sort = data_sort + 1
+    pub fn set_expr<C>(&mut self, column: C, expr: RdbcExprColumn) -> &mut Self
+    where
+        C: RdbcIdent,
+    {
+        self.update.column_value.push(RdbcDmlColumn {
+            column: RdbcColumn::SimpleColumn(RdbcSimpleColumn {
+                column: column.name(),
+            }),
+            value: RdbcDmlValue::COLUMN(RdbcColumn::ExprColumn(expr)),
+        });
+        self
+    }
 }
 impl RdbcTableBuilder for RdbcUpdateBuilder {
     fn table_mut(&mut self) -> &mut Vec<RdbcTable> {
@@ -80,6 +147,29 @@ impl RdbcJoinTableBuilder for RdbcUpdateBuilder {
         self.update.join_table.as_mut()
     }
 }
+impl RdbcWhereFilterBuilder for RdbcUpdateBuilder {
+    fn filter_mut(&mut self) -> &mut RdbcWhereFilter {
+        self.update.where_.get_or_insert(RdbcWhereFilter {
+            type_: RdbcFilterType::And,
+            conditions: vec![],
+        })
+    }
+
+    fn filter_take(&mut self) -> Option<RdbcWhereFilter> {
+        self.update.where_.take()
+    }
+}
+impl RdbcOrderBuilder for RdbcUpdateBuilder {
+    fn order_mut(&mut self) -> &mut Vec<RdbcOrderColumn> {
+        self.update.order_by.as_mut()
+    }
+}
+impl RdbcLimitBuilder for RdbcUpdateBuilder {
+    fn limit(&mut self, limit: u64) -> &mut Self {
+        self.update.limit = Some(limit);
+        self
+    }
+}
 
 pub struct RdbcInsertBuilder {
     insert: RdbcInsert,
@@ -94,12 +184,218 @@ impl RdbcInsertBuilder {
                 values: vec![],
                 column_value: vec![],
                 query: None,
+                conflict: None,
+                returning: vec![],
+                returning_key: None,
+            }
+        }
+    }
+    pub fn build(&self) -> RdbcInsert {
+        self.insert.clone()
+    }
+    pub fn insert_table<T>(&mut self, table: T) -> &mut Self
+    where
+        T: RdbcIdent,
+    {
+        self.table(table)
+    }
+    pub fn insert_column<C>(&mut self, column: C) -> &mut Self
+    where
+        C: RdbcIdent,
+    {
+        self.insert.column.push(column.name());
+        self
+    }
+    pub fn insert_columns<C>(&mut self, columns: Vec<C>) -> &mut Self
+    where
+        C: RdbcIdent,
+    {
+        for column in columns {
+            self.insert_column(column);
+        }
+        self
+    }
+    /// 追加一行值，与 insert_column 指定的列一一对应
+    pub fn insert_values<V>(&mut self, values: Vec<V>) -> &mut Self
+    where
+        RdbcValue: From<V>,
+    {
+        let row = values
+            .into_iter()
+            .map(|value| RdbcDmlValue::VALUE(RdbcValue::from(value)))
+            .collect();
+        self.insert.values.push(row);
+        self
+    }
+    pub fn insert_dml_values(&mut self, values: Vec<RdbcDmlValue>) -> &mut Self {
+        self.insert.values.push(values);
+        self
+    }
+    /// INSERT INTO ... SELECT
+    pub fn insert_query(&mut self, query: RdbcQuery) -> &mut Self {
+        self.insert.query = Some(query);
+        self
+    }
+    pub fn insert_col_val<C, V>(&mut self, column: C, value: V) -> &mut Self
+    where
+        C: RdbcIdent,
+        RdbcValue: From<V>,
+    {
+        self.insert.column_value.push(RdbcDmlColumn {
+            column: RdbcColumn::SimpleColumn(RdbcSimpleColumn {
+                column: column.name(),
+            }),
+            value: RdbcDmlValue::VALUE(RdbcValue::from(value)),
+        });
+        self
+    }
+    pub fn insert_col_col<C, V>(&mut self, column: C, value: V) -> &mut Self
+    where
+        C: RdbcIdent,
+        V: RdbcIdent,
+    {
+        self.insert.column_value.push(RdbcDmlColumn {
+            column: RdbcColumn::SimpleColumn(RdbcSimpleColumn {
+                column: column.name(),
+            }),
+            value: RdbcDmlValue::COLUMN(RdbcColumn::SimpleColumn(RdbcSimpleColumn {
+                column: value.name(),
+            })),
+        });
+        self
+    }
+    pub fn insert_col_func<C>(&mut self, column: C, func: RdbcFunc) -> &mut Self
+    where
+        C: RdbcIdent,
+    {
+        self.insert.column_value.push(RdbcDmlColumn {
+            column: RdbcColumn::SimpleColumn(RdbcSimpleColumn {
+                column: column.name(),
+            }),
+            value: RdbcDmlValue::FUNC(func),
+        });
+        self
+    }
+    pub fn insert_col_expr<C>(&mut self, column: C, expr: RdbcExprColumn) -> &mut Self
+    where
+        C: RdbcIdent,
+    {
+        self.insert.column_value.push(RdbcDmlColumn {
+            column: RdbcColumn::SimpleColumn(RdbcSimpleColumn {
+                column: column.name(),
+            }),
+            value: RdbcDmlValue::COLUMN(RdbcColumn::ExprColumn(expr)),
+        });
+        self
+    }
+    /// 自增或显式赋值的主键列，数据库不支持 RETURNING 时据此回查插入行
+    pub fn returning_key<C>(&mut self, column: C) -> &mut Self
+    where
+        C: RdbcIdent,
+    {
+        self.insert.returning_key = Some(column.name());
+        self
+    }
+    fn conflict_mut(&mut self) -> &mut RdbcConflict {
+        self.insert.conflict.get_or_insert(RdbcConflict {
+            target: None,
+            action: RdbcConflictAction::Nothing,
+        })
+    }
+    /// 追加冲突列，未指定冲突动作时默认忽略冲突行
+    pub fn on_conflict<C>(&mut self, column: C) -> &mut Self
+    where
+        C: RdbcIdent,
+    {
+        let conflict = self.conflict_mut();
+        match conflict.target.as_mut() {
+            Some(RdbcConflictTarget::Columns(columns)) => columns.push(column.name()),
+            _ => conflict.target = Some(RdbcConflictTarget::Columns(vec![column.name()])),
+        }
+        self
+    }
+    pub fn on_conflict_columns<C>(&mut self, columns: Vec<C>) -> &mut Self
+    where
+        C: RdbcIdent,
+    {
+        for column in columns {
+            self.on_conflict(column);
+        }
+        self
+    }
+    /// 按约束名判断冲突，仅 PostgreSQL 支持
+    pub fn on_conflict_constraint<C>(&mut self, constraint: C) -> &mut Self
+    where
+        C: RdbcIdent,
+    {
+        self.conflict_mut().target = Some(RdbcConflictTarget::Constraint(constraint.name()));
+        self
+    }
+    /// 冲突时忽略该行
+    pub fn do_nothing(&mut self) -> &mut Self {
+        self.conflict_mut().action = RdbcConflictAction::Nothing;
+        self
+    }
+    /// 冲突时更新列，值取自待插入行的同名列
+    pub fn do_update<C>(&mut self, column: C) -> &mut Self
+    where
+        C: RdbcIdent,
+    {
+        let name = column.name();
+        self.do_update_conflict_value(name.clone(), RdbcConflictValue::Excluded(name))
+    }
+    pub fn do_update_columns<C>(&mut self, columns: Vec<C>) -> &mut Self
+    where
+        C: RdbcIdent,
+    {
+        for column in columns {
+            self.do_update(column);
+        }
+        self
+    }
+    /// 冲突时将列更新为指定值
+    pub fn do_update_value<C, V>(&mut self, column: C, value: V) -> &mut Self
+    where
+        C: RdbcIdent,
+        RdbcValue: From<V>,
+    {
+        self.do_update_dml_value(column, RdbcDmlValue::VALUE(RdbcValue::from(value)))
+    }
+    pub fn do_update_dml_value<C>(&mut self, column: C, value: RdbcDmlValue) -> &mut Self
+    where
+        C: RdbcIdent,
+    {
+        self.do_update_conflict_value(column.name(), RdbcConflictValue::Value(Box::new(value)))
+    }
+    fn do_update_conflict_value(&mut self, column: String, value: RdbcConflictValue) -> &mut Self {
+        let conflict = self.conflict_mut();
+        let update = RdbcConflictColumn { column, value };
+        match &mut conflict.action {
+            RdbcConflictAction::Update(columns) => columns.push(update),
+            RdbcConflictAction::Nothing => {
+                conflict.action = RdbcConflictAction::Update(vec![update])
             }
         }
+        self
     }
 }
 impl RdbcTableBuilder for RdbcInsertBuilder {
     fn table_mut(&mut self) -> &mut Vec<RdbcTable> {
         self.insert.table.as_mut()
     }
+}
+impl RdbcReturningBuilder for RdbcInsertBuilder {
+    fn returning_mut(&mut self) -> &mut Vec<String> {
+        self.insert.returning.as_mut()
+    }
+}
+impl RdbcReturningBuilder for RdbcUpdateBuilder {
+    fn returning_mut(&mut self) -> &mut Vec<String> {
+        self.update.returning.as_mut()
+    }
+}
+impl RdbcReturningBuilder for RdbcDeleteBuilder {
+    fn returning_mut(&mut self) -> &mut Vec<String> {
+        self.delete.returning.as_mut()
+    }
 }
\ No newline at end of file
@@ -1,14 +1,18 @@
+use crate::builder::dmq::distinct::RdbcDistinctBuilder;
 use crate::builder::dmq::filter::RdbcWhereFilterBuilder;
 use crate::builder::dmq::group::RdbcGroupBuilder;
 use crate::builder::dmq::having::RdbcHavingBuilder;
 use crate::builder::dmq::limit::{RdbcLimitBuilder, RdbcOffsetBuilder};
+use crate::builder::dmq::lock::RdbcLockBuilder;
 use crate::builder::dmq::order::RdbcOrderBuilder;
 use crate::builder::dmq::select::RdbcSelectBuilder;
 use crate::builder::dmq::table::{RdbcJoinTableBuilder, RdbcTableBuilder};
-use crate::builder::dmq::union::{RdbcUnionAllBuilder, RdbcUnionBuilder};
+use crate::builder::dmq::union::{RdbcCompoundBuilder, RdbcUnionAllBuilder, RdbcUnionBuilder};
+use crate::builder::dmq::with::RdbcWithBuilder;
 use crate::{
-    RdbcFilterType, RdbcGroupColumn, RdbcHaving, RdbcJoinTable, RdbcOrderColumn, RdbcQuery,
-    RdbcSelectColumn, RdbcTable, RdbcWhereFilter,
+    RdbcColumn, RdbcCompoundQuery, RdbcFilterType, RdbcGroupColumn, RdbcHaving, RdbcJoinTable,
+    RdbcLock, RdbcOrderColumn, RdbcQuery, RdbcSelectColumn, RdbcTable, RdbcWhereFilter,
+    RdbcWithTable,
 };
 
 pub struct RdbcQueryBuilder {
@@ -19,6 +23,9 @@ impl RdbcQueryBuilder {
     pub fn new() -> Self {
         RdbcQueryBuilder {
             query: RdbcQuery {
+                with: vec![],
+                distinct: false,
+                distinct_on: vec![],
                 select: vec![],
                 table: vec![],
                 join_table: vec![],
@@ -28,11 +35,32 @@ impl RdbcQueryBuilder {
                 having: None,
                 limit: None,
                 offset: None,
-                union: vec![],
-                union_all: vec![],
+                compound: vec![],
+                compound_order_by: vec![],
+                compound_limit: None,
+                compound_offset: None,
+                lock: None,
             },
         }
     }
+    pub fn build(&self) -> RdbcQuery {
+        self.query.clone()
+    }
+}
+
+impl RdbcWithBuilder for RdbcQueryBuilder {
+    fn with_mut(&mut self) -> &mut Vec<RdbcWithTable> {
+        self.query.with.as_mut()
+    }
+}
+
+impl RdbcDistinctBuilder for RdbcQueryBuilder {
+    fn distinct_mut(&mut self) -> &mut bool {
+        &mut self.query.distinct
+    }
+    fn distinct_on_mut(&mut self) -> &mut Vec<RdbcColumn> {
+        self.query.distinct_on.as_mut()
+    }
 }
 
 impl RdbcSelectBuilder for RdbcQueryBuilder {
@@ -58,7 +86,6 @@ impl RdbcWhereFilterBuilder for RdbcQueryBuilder {
         self.query.where_.get_or_insert(RdbcWhereFilter {
             type_: RdbcFilterType::And,
             conditions: vec![],
-            distinct: false,
         })
     }
     fn filter_take(&mut self) -> Option<RdbcWhereFilter> {
@@ -84,7 +111,6 @@ impl RdbcHavingBuilder for RdbcQueryBuilder {
             filter: Some(RdbcWhereFilter {
                 type_: RdbcFilterType::And,
                 conditions: vec![],
-                distinct: false,
             }),
         })
     }
@@ -101,15 +127,26 @@ impl RdbcOffsetBuilder for RdbcQueryBuilder {
         self
     }
 }
-impl RdbcUnionBuilder for RdbcQueryBuilder {
-    fn union(&mut self, query: RdbcQuery) -> &mut Self {
-        self.query.union.push(query);
-        self
+impl RdbcLockBuilder for RdbcQueryBuilder {
+    fn lock_mut(&mut self) -> &mut Option<RdbcLock> {
+        &mut self.query.lock
     }
 }
-impl RdbcUnionAllBuilder for RdbcQueryBuilder {
-    fn union_all(&mut self, query: RdbcQuery) -> &mut Self {
-        self.query.union_all.push(query);
+impl RdbcCompoundBuilder for RdbcQueryBuilder {
+    fn compound_mut(&mut self) -> &mut Vec<RdbcCompoundQuery> {
+        self.query.compound.as_mut()
+    }
+    fn compound_order_mut(&mut self) -> &mut Vec<RdbcOrderColumn> {
+        self.query.compound_order_by.as_mut()
+    }
+    fn compound_limit(&mut self, limit: u64) -> &mut Self {
+        self.query.compound_limit = Some(limit);
+        self
+    }
+    fn compound_offset(&mut self, offset: u64) -> &mut Self {
+        self.query.compound_offset = Some(offset);
         self
     }
 }
+impl RdbcUnionBuilder for RdbcQueryBuilder {}
+impl RdbcUnionAllBuilder for RdbcQueryBuilder {}
@@ -0,0 +1,152 @@
+use crate::{
+    RdbcBinaryExpr, RdbcCastExpr, RdbcColumn, RdbcExprColumn, RdbcOperator, RdbcValueColumn,
+};
+use bmbp_rdbc_type::RdbcValue;
+
+/// 运算表达式构建，运算数可为列名、RdbcColumn、RdbcFunc 或嵌套的 RdbcExprColumn，
+/// `*_v` 系列的右侧常量以绑定参数方式渲染
+pub struct RdbcExprBuilder;
+
+impl RdbcExprBuilder {
+    pub fn binary<L, R>(left: L, operator: RdbcOperator, right: R) -> RdbcExprColumn
+    where
+        RdbcColumn: From<L>,
+        RdbcColumn: From<R>,
+    {
+        RdbcExprColumn::Binary(RdbcBinaryExpr {
+            left: Box::new(RdbcColumn::from(left)),
+            operator,
+            right: Box::new(RdbcColumn::from(right)),
+        })
+    }
+    pub fn add<L, R>(left: L, right: R) -> RdbcExprColumn
+    where
+        RdbcColumn: From<L>,
+        RdbcColumn: From<R>,
+    {
+        Self::binary(left, RdbcOperator::Add, right)
+    }
+    pub fn sub<L, R>(left: L, right: R) -> RdbcExprColumn
+    where
+        RdbcColumn: From<L>,
+        RdbcColumn: From<R>,
+    {
+        Self::binary(left, RdbcOperator::Sub, right)
+    }
+    pub fn mul<L, R>(left: L, right: R) -> RdbcExprColumn
+    where
+        RdbcColumn: From<L>,
+        RdbcColumn: From<R>,
+    {
+        Self::binary(left, RdbcOperator::Mul, right)
+    }
+    pub fn div<L, R>(left: L, right: R) -> RdbcExprColumn
+    where
+        RdbcColumn: From<L>,
+        RdbcColumn: From<R>,
+    {
+        Self::binary(left, RdbcOperator::Div, right)
+    }
+    pub fn modulo<L, R>(left: L, right: R) -> RdbcExprColumn
+    where
+        RdbcColumn: From<L>,
+        RdbcColumn: From<R>,
+    {
+        Self::binary(left, RdbcOperator::Mod, right)
+    }
+    pub fn concat<L, R>(left: L, right: R) -> RdbcExprColumn
+    where
+        RdbcColumn: From<L>,
+        RdbcColumn: From<R>,
+    {
+        Self::binary(left, RdbcOperator::Concat, right)
+    }
+    pub fn add_v<L, V>(left: L, value: V) -> RdbcExprColumn
+    where
+        RdbcColumn: From<L>,
+        RdbcValue: From<V>,
+    {
+        Self::binary::<L, RdbcColumn>(
+            left,
+            RdbcOperator::Add,
+            value_column(RdbcValue::from(value)),
+        )
+    }
+    pub fn sub_v<L, V>(left: L, value: V) -> RdbcExprColumn
+    where
+        RdbcColumn: From<L>,
+        RdbcValue: From<V>,
+    {
+        Self::binary::<L, RdbcColumn>(
+            left,
+            RdbcOperator::Sub,
+            value_column(RdbcValue::from(value)),
+        )
+    }
+    pub fn mul_v<L, V>(left: L, value: V) -> RdbcExprColumn
+    where
+        RdbcColumn: From<L>,
+        RdbcValue: From<V>,
+    {
+        Self::binary::<L, RdbcColumn>(
+            left,
+            RdbcOperator::Mul,
+            value_column(RdbcValue::from(value)),
+        )
+    }
+    pub fn div_v<L, V>(left: L, value: V) -> RdbcExprColumn
+    where
+        RdbcColumn: From<L>,
+        RdbcValue: From<V>,
+    {
+        Self::binary::<L, RdbcColumn>(
+            left,
+            RdbcOperator::Div,
+            value_column(RdbcValue::from(value)),
+        )
+    }
+    pub fn modulo_v<L, V>(left: L, value: V) -> RdbcExprColumn
+    where
+        RdbcColumn: From<L>,
+        RdbcValue: From<V>,
+    {
+        Self::binary::<L, RdbcColumn>(
+            left,
+            RdbcOperator::Mod,
+            value_column(RdbcValue::from(value)),
+        )
+    }
+    pub fn concat_v<L, V>(left: L, value: V) -> RdbcExprColumn
+    where
+        RdbcColumn: From<L>,
+        RdbcValue: From<V>,
+    {
+        Self::binary::<L, RdbcColumn>(
+            left,
+            RdbcOperator::Concat,
+            value_column(RdbcValue::from(value)),
+        )
+    }
+    /// -x
+    pub fn neg<C>(column: C) -> RdbcExprColumn
+    where
+        RdbcColumn: From<C>,
+    {
+        RdbcExprColumn::Negative(Box::new(RdbcColumn::from(column)))
+    }
+    /// CAST(x AS type)，type 原样输出，仅允许字母、数字、下划线、空格、括号与逗号
+    pub fn cast<C, T>(column: C, type_: T) -> RdbcExprColumn
+    where
+        RdbcColumn: From<C>,
+        T: ToString,
+    {
+        RdbcExprColumn::Cast(RdbcCastExpr {
+            column: Box::new(RdbcColumn::from(column)),
+            type_: type_.to_string(),
+        })
+    }
+}
+
+fn value_column(column: RdbcValue) -> RdbcColumn {
+    RdbcColumn::ValueColumn(RdbcValueColumn { column })
+}
@@ -1,20 +1,15 @@
 use crate::{
-    RdbcColumn, RdbcCompare, RdbcFilterType, RdbcFilterValue, RdbcQuery, RdbcSimpleColumn,
-    RdbcValueColumn, RdbcWhereCondition, RdbcWhereFilter, RdbcWhereNestCondition,
-    RdbcWhereRawCondition, RdbcWhereSimpleCondition,
+    RdbcColumn, RdbcCompare, RdbcFilterType, RdbcFilterValue, RdbcQuery, RdbcRawColumn,
+    RdbcSimpleColumn, RdbcValueColumn, RdbcValueSkip, RdbcWhereCondition, RdbcWhereFilter,
+    RdbcWhereNestCondition, RdbcWhereRawCondition, RdbcWhereSimpleCondition,
 };
-use bmbp_rdbc_type::{RdbcIdent, RdbcValue};
+use bmbp_rdbc_type::{RdbcColumnIdent, RdbcIdent, RdbcValue};
 use crate::RdbcCompare::{IsNotNull, IsNull};
 
 pub trait RdbcWhereFilterBuilder {
     fn filter_mut(&mut self) -> &mut RdbcWhereFilter;
     fn filter_take(&mut self) -> Option<RdbcWhereFilter>;
 
-    fn distinct(&mut self) -> &mut Self {
-        self.filter_mut().distinct = true;
-        self
-    }
-
     fn simple_value<C, V>(&mut self, column: C, compare: RdbcCompare, value: V) -> &mut Self
     where
         C: RdbcIdent,
@@ -43,7 +38,7 @@ pub trait RdbcWhereFilterBuilder {
             }),
             compare,
             value: RdbcFilterValue::Column(RdbcColumn::SimpleColumn(RdbcSimpleColumn {
-                column: column.name(),
+                column: value.name(),
             })),
         };
         let where_condition = RdbcWhereCondition::Simple(simple);
@@ -75,7 +70,7 @@ pub trait RdbcWhereFilterBuilder {
                 column: column.name(),
             }),
             compare,
-            value: RdbcFilterValue::Value(RdbcValue::Varchar(value.name())),
+            value: RdbcFilterValue::Script(value.name()),
         };
         let where_condition = RdbcWhereCondition::Simple(simple);
         self.filter_mut().conditions.push(where_condition);
@@ -237,14 +232,14 @@ pub trait RdbcWhereFilterBuilder {
         C: RdbcIdent,
         V: RdbcIdent,
     {
-        self.simple_script(column, RdbcCompare::GE, value)
+        self.simple_script(column, RdbcCompare::LE, value)
     }
     fn le_raw<C, V>(&mut self, column: C, value: V) -> &mut Self
     where
         C: RdbcIdent,
         V: RdbcIdent,
     {
-        self.simple_raw(column, RdbcCompare::GE, value)
+        self.simple_raw(column, RdbcCompare::LE, value)
     }
 
     fn lt_v<C, V>(&mut self, column: C, value: V) -> &mut Self
@@ -252,14 +247,14 @@ pub trait RdbcWhereFilterBuilder {
         C: RdbcIdent,
         RdbcValue: From<V>,
     {
-        self.simple_value(column, RdbcCompare::GT, value)
+        self.simple_value(column, RdbcCompare::LT, value)
     }
     fn lt_col<C, V>(&mut self, column: C, value: V) -> &mut Self
     where
         C: RdbcIdent,
         V: RdbcIdent,
     {
-        self.simple_col(column, RdbcCompare::GT, value)
+        self.simple_col(column, RdbcCompare::LT, value)
     }
 
     fn lt_script<C, V>(&mut self, column: C, value: V) -> &mut Self
@@ -267,14 +262,14 @@ pub trait RdbcWhereFilterBuilder {
         C: RdbcIdent,
         V: RdbcIdent,
     {
-        self.simple_script(column, RdbcCompare::GT, value)
+        self.simple_script(column, RdbcCompare::LT, value)
     }
     fn lt_raw<C, V>(&mut self, column: C, value: V) -> &mut Self
     where
         C: RdbcIdent,
         V: RdbcIdent,
     {
-        self.simple_raw(column, RdbcCompare::GT, value)
+        self.simple_raw(column, RdbcCompare::LT, value)
     }
 
     fn like_v<C, V>(&mut self, column: C, value: V) -> &mut Self
@@ -414,23 +409,30 @@ pub trait RdbcWhereFilterBuilder {
         self.simple_raw(column, RdbcCompare::NotIn, value.name())
     }
 
-    fn exists<C>(&mut self, column: C, value: RdbcQuery) -> &mut Self
-    where
-        C: RdbcIdent,
-    {
-        self.simple_query(column, RdbcCompare::Exists, value)
+    /// EXISTS 子查询，无左侧列
+    fn exists(&mut self, value: RdbcQuery) -> &mut Self {
+        self.simple_exists(RdbcCompare::Exists, value)
     }
-    fn not_exists<C>(&mut self, column: C, value: RdbcQuery) -> &mut Self
-    where
-        C: RdbcIdent,
-    {
-        self.simple_query(column, RdbcCompare::Exists, value)
+    fn not_exists(&mut self, value: RdbcQuery) -> &mut Self {
+        self.simple_exists(RdbcCompare::NotExits, value)
+    }
+    fn simple_exists(&mut self, compare: RdbcCompare, value: RdbcQuery) -> &mut Self {
+        let simple = RdbcWhereSimpleCondition {
+            column: RdbcColumn::RawColumn(RdbcRawColumn {
+                column: "".to_string(),
+            }),
+            compare,
+            value: RdbcFilterValue::Query(value),
+        };
+        self.filter_mut()
+            .conditions
+            .push(RdbcWhereCondition::Simple(simple));
+        self
     }
 
-    fn null<C, V>(&mut self, column: C) -> &mut Self
+    fn null<C>(&mut self, column: C) -> &mut Self
     where
         C: RdbcIdent,
-        V: RdbcIdent,
     {
         let simple = RdbcWhereSimpleCondition {
             column: RdbcColumn::SimpleColumn(RdbcSimpleColumn {
@@ -443,10 +445,9 @@ pub trait RdbcWhereFilterBuilder {
         self.filter_mut().conditions.push(where_condition);
         self
     }
-    fn not_null<C, V>(&mut self, column: C) -> &mut Self
+    fn not_null<C>(&mut self, column: C) -> &mut Self
     where
         C: RdbcIdent,
-        V: RdbcIdent,
     {
         let simple = RdbcWhereSimpleCondition {
             column: RdbcColumn::SimpleColumn(RdbcSimpleColumn {
@@ -469,7 +470,7 @@ pub trait RdbcWhereFilterBuilder {
         let rdbc_value = RdbcValue::Array(vec![RdbcValue::from(start), RdbcValue::from(end)]);
         self.simple_value(column, RdbcCompare::Between, rdbc_value)
     }
-    fn not_between_v<C, V, E>(&mut self, column: C, start: V, end: V) -> &mut Self
+    fn not_between_v<C, V, E>(&mut self, column: C, start: V, end: E) -> &mut Self
     where
         C: RdbcIdent,
         RdbcValue: From<V>,
@@ -479,6 +480,155 @@ pub trait RdbcWhereFilterBuilder {
         self.simple_value(column, RdbcCompare::NotBetween, rdbc_value)
     }
 
+    /// 按跳过规则追加值条件，值被跳过时不追加
+    fn simple_value_skip<C, V>(
+        &mut self,
+        column: C,
+        compare: RdbcCompare,
+        value: V,
+        skip: RdbcValueSkip,
+    ) -> &mut Self
+    where
+        C: RdbcIdent,
+        RdbcValue: From<V>,
+    {
+        let value = RdbcValue::from(value);
+        if skip.is_skip(&value) {
+            return self;
+        }
+        self.simple_value::<C, RdbcValue>(column, compare, value)
+    }
+    fn simple_value_opt<C, V>(&mut self, column: C, compare: RdbcCompare, value: V) -> &mut Self
+    where
+        C: RdbcIdent,
+        RdbcValue: From<V>,
+    {
+        self.simple_value_skip(column, compare, value, RdbcValueSkip::Both)
+    }
+    /// 条件成立时才执行 build 追加过滤条件
+    fn filter_if<F>(&mut self, condition: bool, build: F) -> &mut Self
+    where
+        F: FnOnce(&mut Self),
+    {
+        if condition {
+            build(self);
+        }
+        self
+    }
+    /// 值为 None 或空串时不追加条件
+    fn eq_v_opt<C, V>(&mut self, column: C, value: V) -> &mut Self
+    where
+        C: RdbcIdent,
+        RdbcValue: From<V>,
+    {
+        self.simple_value_opt(column, RdbcCompare::EQ, value)
+    }
+    fn ne_v_opt<C, V>(&mut self, column: C, value: V) -> &mut Self
+    where
+        C: RdbcIdent,
+        RdbcValue: From<V>,
+    {
+        self.simple_value_opt(column, RdbcCompare::NE, value)
+    }
+    fn ge_v_opt<C, V>(&mut self, column: C, value: V) -> &mut Self
+    where
+        C: RdbcIdent,
+        RdbcValue: From<V>,
+    {
+        self.simple_value_opt(column, RdbcCompare::GE, value)
+    }
+    fn gt_v_opt<C, V>(&mut self, column: C, value: V) -> &mut Self
+    where
+        C: RdbcIdent,
+        RdbcValue: From<V>,
+    {
+        self.simple_value_opt(column, RdbcCompare::GT, value)
+    }
+    fn le_v_opt<C, V>(&mut self, column: C, value: V) -> &mut Self
+    where
+        C: RdbcIdent,
+        RdbcValue: From<V>,
+    {
+        self.simple_value_opt(column, RdbcCompare::LE, value)
+    }
+    fn lt_v_opt<C, V>(&mut self, column: C, value: V) -> &mut Self
+    where
+        C: RdbcIdent,
+        RdbcValue: From<V>,
+    {
+        self.simple_value_opt(column, RdbcCompare::LT, value)
+    }
+    fn like_v_opt<C, V>(&mut self, column: C, value: V) -> &mut Self
+    where
+        C: RdbcIdent,
+        RdbcValue: From<V>,
+    {
+        self.simple_value_opt(column, RdbcCompare::Like, value)
+    }
+    fn like_left_v_opt<C, V>(&mut self, column: C, value: V) -> &mut Self
+    where
+        C: RdbcIdent,
+        RdbcValue: From<V>,
+    {
+        self.simple_value_opt(column, RdbcCompare::LikeLeft, value)
+    }
+    fn like_right_v_opt<C, V>(&mut self, column: C, value: V) -> &mut Self
+    where
+        C: RdbcIdent,
+        RdbcValue: From<V>,
+    {
+        self.simple_value_opt(column, RdbcCompare::LikeRight, value)
+    }
+    fn not_like_v_opt<C, V>(&mut self, column: C, value: V) -> &mut Self
+    where
+        C: RdbcIdent,
+        RdbcValue: From<V>,
+    {
+        self.simple_value_opt(column, RdbcCompare::NotLike, value)
+    }
+    /// 值为 None 或空数组时不追加条件，可直接传入 Vec 或 Option<Vec>
+    fn in_v_opt<C, V, O>(&mut self, column: C, value: O) -> &mut Self
+    where
+        C: RdbcIdent,
+        O: Into<Option<Vec<V>>>,
+        RdbcValue: From<Vec<V>>,
+    {
+        match value.into() {
+            Some(value) => self.simple_value_opt(column, RdbcCompare::IN, value),
+            None => self,
+        }
+    }
+    fn not_in_v_opt<C, V, O>(&mut self, column: C, value: O) -> &mut Self
+    where
+        C: RdbcIdent,
+        O: Into<Option<Vec<V>>>,
+        RdbcValue: From<Vec<V>>,
+    {
+        match value.into() {
+            Some(value) => self.simple_value_opt(column, RdbcCompare::NotIn, value),
+            None => self,
+        }
+    }
+    /// 区间的任一端被跳过时退化为单侧比较，两端均被跳过时不追加条件
+    fn between_v_opt<C, V, E>(&mut self, column: C, start: V, end: E) -> &mut Self
+    where
+        C: RdbcIdent,
+        RdbcValue: From<V>,
+        RdbcValue: From<E>,
+    {
+        let start = RdbcValue::from(start);
+        let end = RdbcValue::from(end);
+        match (
+            RdbcValueSkip::Both.is_skip(&start),
+            RdbcValueSkip::Both.is_skip(&end),
+        ) {
+            (false, false) => self.between_v(column, start, end),
+            (false, true) => self.ge_v(column, start),
+            (true, false) => self.le_v(column, end),
+            (true, true) => self,
+        }
+    }
+
     fn nest(&mut self, filter: RdbcWhereFilter) -> &mut Self {
         let simple_filter = RdbcWhereCondition::Nest(RdbcWhereNestCondition { condition: filter });
         self.filter_mut().conditions.push(simple_filter);
@@ -534,4 +684,125 @@ pub trait RdbcWhereFilterBuilder {
         self.filter_mut().conditions.push(simple);
         self
     }
+    /// 函数等任意列与值比较
+    fn rdbc_column_v<C, V>(&mut self, column: C, compare: RdbcCompare, value: V) -> &mut Self
+    where
+        RdbcColumn: From<C>,
+        RdbcValue: From<V>,
+    {
+        let simple = RdbcWhereSimpleCondition {
+            column: RdbcColumn::from(column),
+            compare,
+            value: RdbcFilterValue::Value(RdbcValue::from(value)),
+        };
+        self.filter_mut()
+            .conditions
+            .push(RdbcWhereCondition::Simple(simple));
+        self
+    }
+    /// 类型化列与值比较，列渲染为 alias.column
+    fn ident_v<C, V>(&mut self, _column: C, compare: RdbcCompare, value: V) -> &mut Self
+    where
+        C: RdbcColumnIdent,
+        RdbcValue: From<V>,
+    {
+        self.rdbc_column_v(RdbcColumn::column_ident::<C>(), compare, value)
+    }
+    /// 两个类型化列比较，用于关联条件
+    fn ident_col<C, R>(&mut self, _column: C, compare: RdbcCompare, _value: R) -> &mut Self
+    where
+        C: RdbcColumnIdent,
+        R: RdbcColumnIdent,
+    {
+        let simple = RdbcWhereSimpleCondition {
+            column: RdbcColumn::column_ident::<C>(),
+            compare,
+            value: RdbcFilterValue::Column(RdbcColumn::column_ident::<R>()),
+        };
+        self.filter_mut()
+            .conditions
+            .push(RdbcWhereCondition::Simple(simple));
+        self
+    }
+    fn eq_ident_v<C, V>(&mut self, column: C, value: V) -> &mut Self
+    where
+        C: RdbcColumnIdent,
+        RdbcValue: From<V>,
+    {
+        self.ident_v(column, RdbcCompare::EQ, value)
+    }
+    fn ne_ident_v<C, V>(&mut self, column: C, value: V) -> &mut Self
+    where
+        C: RdbcColumnIdent,
+        RdbcValue: From<V>,
+    {
+        self.ident_v(column, RdbcCompare::NE, value)
+    }
+    fn ge_ident_v<C, V>(&mut self, column: C, value: V) -> &mut Self
+    where
+        C: RdbcColumnIdent,
+        RdbcValue: From<V>,
+    {
+        self.ident_v(column, RdbcCompare::GE, value)
+    }
+    fn gt_ident_v<C, V>(&mut self, column: C, value: V) -> &mut Self
+    where
+        C: RdbcColumnIdent,
+        RdbcValue: From<V>,
+    {
+        self.ident_v(column, RdbcCompare::GT, value)
+    }
+    fn le_ident_v<C, V>(&mut self, column: C, value: V) -> &mut Self
+    where
+        C: RdbcColumnIdent,
+        RdbcValue: From<V>,
+    {
+        self.ident_v(column, RdbcCompare::LE, value)
+    }
+    fn lt_ident_v<C, V>(&mut self, column: C, value: V) -> &mut Self
+    where
+        C: RdbcColumnIdent,
+        RdbcValue: From<V>,
+    {
+        self.ident_v(column, RdbcCompare::LT, value)
+    }
+    fn like_ident_v<C, V>(&mut self, column: C, value: V) -> &mut Self
+    where
+        C: RdbcColumnIdent,
+        RdbcValue: From<V>,
+    {
+        self.ident_v(column, RdbcCompare::Like, value)
+    }
+    fn in_ident_v<C, V>(&mut self, column: C, value: Vec<V>) -> &mut Self
+    where
+        C: RdbcColumnIdent,
+        RdbcValue: From<Vec<V>>,
+    {
+        self.ident_v(column, RdbcCompare::IN, value)
+    }
+    fn eq_ident_col<C, R>(&mut self, column: C, value: R) -> &mut Self
+    where
+        C: RdbcColumnIdent,
+        R: RdbcColumnIdent,
+    {
+        self.ident_col(column, RdbcCompare::EQ, value)
+    }
+    /// 原始列表达式与值比较，列表达式原样写入 SQL，不做标识符校验
+    fn raw_column_v<C, V>(&mut self, column: C, compare: RdbcCompare, value: V) -> &mut Self
+    where
+        C: RdbcIdent,
+        RdbcValue: From<V>,
+    {
+        let simple = RdbcWhereSimpleCondition {
+            column: RdbcColumn::RawColumn(RdbcRawColumn {
+                column: column.name(),
+            }),
+            compare,
+            value: RdbcFilterValue::Value(RdbcValue::from(value)),
+        };
+        self.filter_mut()
+            .conditions
+            .push(RdbcWhereCondition::Simple(simple));
+        self
+    }
 }
@@ -0,0 +1,234 @@
+use crate::{
+    RdbcAbsFunc, RdbcArgsFunc, RdbcColumn, RdbcColumnFunc, RdbcCountFunc, RdbcDateAddFunc,
+    RdbcDateFunc, RdbcDateUnit, RdbcDistinctFunc, RdbcFunc, RdbcRoundFunc, RdbcStringAggFunc,
+    RdbcSubstringFunc, RdbcSumFunc, RdbcSumFuncColumn, RdbcValueColumn,
+};
+use bmbp_rdbc_type::RdbcValue;
+
+/// 函数构建，参数可为列名、RdbcColumn 或嵌套的 RdbcFunc，
+/// 常量参数使用 RdbcValue 以绑定参数方式渲染
+pub struct RdbcFuncBuilder;
+
+impl RdbcFuncBuilder {
+    pub fn distinct<C>(columns: Vec<C>) -> RdbcFunc
+    where
+        RdbcColumn: From<C>,
+    {
+        RdbcFunc::DISTINCT(RdbcDistinctFunc {
+            columns: columns.into_iter().map(RdbcColumn::from).collect(),
+        })
+    }
+    pub fn abs<C>(column: C) -> RdbcFunc
+    where
+        RdbcColumn: From<C>,
+    {
+        RdbcFunc::ABS(RdbcAbsFunc {
+            column: vec![RdbcColumn::from(column)],
+        })
+    }
+    pub fn sum<C>(column: C) -> RdbcFunc
+    where
+        RdbcColumn: From<C>,
+    {
+        RdbcFunc::SUM(RdbcSumFunc {
+            column: vec![RdbcSumFuncColumn::Column(RdbcColumn::from(column))],
+        })
+    }
+    /// COUNT(*)
+    pub fn count_all() -> RdbcFunc {
+        RdbcFunc::COUNT(RdbcCountFunc {
+            column: vec![],
+            distinct: false,
+        })
+    }
+    pub fn count<C>(column: C) -> RdbcFunc
+    where
+        RdbcColumn: From<C>,
+    {
+        RdbcFunc::COUNT(RdbcCountFunc {
+            column: vec![RdbcColumn::from(column)],
+            distinct: false,
+        })
+    }
+    pub fn count_distinct<C>(column: C) -> RdbcFunc
+    where
+        RdbcColumn: From<C>,
+    {
+        RdbcFunc::COUNT(RdbcCountFunc {
+            column: vec![RdbcColumn::from(column)],
+            distinct: true,
+        })
+    }
+    pub fn min<C>(column: C) -> RdbcFunc
+    where
+        RdbcColumn: From<C>,
+    {
+        RdbcFunc::MIN(column_func(column))
+    }
+    pub fn max<C>(column: C) -> RdbcFunc
+    where
+        RdbcColumn: From<C>,
+    {
+        RdbcFunc::MAX(column_func(column))
+    }
+    pub fn avg<C>(column: C) -> RdbcFunc
+    where
+        RdbcColumn: From<C>,
+    {
+        RdbcFunc::AVG(column_func(column))
+    }
+    pub fn string_agg<C, S>(column: C, separator: S) -> RdbcFunc
+    where
+        RdbcColumn: From<C>,
+        S: ToString,
+    {
+        RdbcFunc::StringAgg(RdbcStringAggFunc {
+            column: Box::new(RdbcColumn::from(column)),
+            separator: separator.to_string(),
+        })
+    }
+    pub fn coalesce(args: Vec<RdbcColumn>) -> RdbcFunc {
+        RdbcFunc::COALESCE(RdbcArgsFunc { args })
+    }
+    /// COALESCE(column, default)，default 以绑定参数方式渲染
+    pub fn coalesce_v<C, V>(column: C, default: V) -> RdbcFunc
+    where
+        RdbcColumn: From<C>,
+        RdbcValue: From<V>,
+    {
+        Self::coalesce(vec![
+            RdbcColumn::from(column),
+            value_column(RdbcValue::from(default)),
+        ])
+    }
+    pub fn nullif<C, E>(column: C, expr: E) -> RdbcFunc
+    where
+        RdbcColumn: From<C>,
+        RdbcColumn: From<E>,
+    {
+        RdbcFunc::NULLIF(RdbcArgsFunc {
+            args: vec![RdbcColumn::from(column), RdbcColumn::from(expr)],
+        })
+    }
+    pub fn concat(args: Vec<RdbcColumn>) -> RdbcFunc {
+        RdbcFunc::CONCAT(RdbcArgsFunc { args })
+    }
+    pub fn upper<C>(column: C) -> RdbcFunc
+    where
+        RdbcColumn: From<C>,
+    {
+        RdbcFunc::UPPER(column_func(column))
+    }
+    pub fn lower<C>(column: C) -> RdbcFunc
+    where
+        RdbcColumn: From<C>,
+    {
+        RdbcFunc::LOWER(column_func(column))
+    }
+    pub fn trim<C>(column: C) -> RdbcFunc
+    where
+        RdbcColumn: From<C>,
+    {
+        RdbcFunc::TRIM(column_func(column))
+    }
+    /// 截取字符串，start 从 1 开始
+    pub fn substring<C>(column: C, start: i64, length: Option<i64>) -> RdbcFunc
+    where
+        RdbcColumn: From<C>,
+    {
+        RdbcFunc::SUBSTRING(RdbcSubstringFunc {
+            column: Box::new(RdbcColumn::from(column)),
+            start,
+            length,
+        })
+    }
+    pub fn length<C>(column: C) -> RdbcFunc
+    where
+        RdbcColumn: From<C>,
+    {
+        RdbcFunc::LENGTH(column_func(column))
+    }
+    pub fn replace<C, F, T>(column: C, from: F, to: T) -> RdbcFunc
+    where
+        RdbcColumn: From<C>,
+        RdbcValue: From<F>,
+        RdbcValue: From<T>,
+    {
+        RdbcFunc::REPLACE(RdbcArgsFunc {
+            args: vec![
+                RdbcColumn::from(column),
+                value_column(RdbcValue::from(from)),
+                value_column(RdbcValue::from(to)),
+            ],
+        })
+    }
+    pub fn now() -> RdbcFunc {
+        RdbcFunc::NOW
+    }
+    pub fn current_date() -> RdbcFunc {
+        RdbcFunc::CurrentDate
+    }
+    pub fn date_trunc<C>(unit: RdbcDateUnit, column: C) -> RdbcFunc
+    where
+        RdbcColumn: From<C>,
+    {
+        RdbcFunc::DateTrunc(RdbcDateFunc {
+            unit,
+            column: Box::new(RdbcColumn::from(column)),
+        })
+    }
+    pub fn extract<C>(unit: RdbcDateUnit, column: C) -> RdbcFunc
+    where
+        RdbcColumn: From<C>,
+    {
+        RdbcFunc::EXTRACT(RdbcDateFunc {
+            unit,
+            column: Box::new(RdbcColumn::from(column)),
+        })
+    }
+    /// 日期加减，amount 为负数时表示减
+    pub fn date_add<C>(column: C, amount: i64, unit: RdbcDateUnit) -> RdbcFunc
+    where
+        RdbcColumn: From<C>,
+    {
+        RdbcFunc::DateAdd(RdbcDateAddFunc {
+            column: Box::new(RdbcColumn::from(column)),
+            amount,
+            unit,
+        })
+    }
+    pub fn round<C>(column: C, scale: Option<i32>) -> RdbcFunc
+    where
+        RdbcColumn: From<C>,
+    {
+        RdbcFunc::ROUND(RdbcRoundFunc {
+            column: Box::new(RdbcColumn::from(column)),
+            scale,
+        })
+    }
+    pub fn floor<C>(column: C) -> RdbcFunc
+    where
+        RdbcColumn: From<C>,
+    {
+        RdbcFunc::FLOOR(column_func(column))
+    }
+    pub fn ceil<C>(column: C) -> RdbcFunc
+    where
+        RdbcColumn: From<C>,
+    {
+        RdbcFunc::CEIL(column_func(column))
+    }
+}
+
+fn column_func<C>(column: C) -> RdbcColumnFunc
+where
+    RdbcColumn: From<C>,
+{
+    RdbcColumnFunc {
+        column: Box::new(RdbcColumn::from(column)),
+    }
+}
+
+fn value_column(column: RdbcValue) -> RdbcColumn {
+    RdbcColumn::ValueColumn(RdbcValueColumn { column })
+}
@@ -1,8 +1,9 @@
 use crate::{
-    RdbcColumn, RdbcFuncColumn, RdbcGroupColumn, RdbcQuery, RdbcQueryColumn, RdbcRawColumn,
-    RdbcSelectColumn, RdbcSimpleColumn, RdbcTableColumn, RdbcValueColumn,
+    RdbcCaseColumn, RdbcColumn, RdbcFuncColumn, RdbcGroupColumn, RdbcLiteralColumn, RdbcQuery,
+    RdbcQueryColumn, RdbcRawColumn, RdbcSelectColumn, RdbcSimpleColumn, RdbcTableColumn,
+    RdbcValueColumn,
 };
-use bmbp_rdbc_type::{RdbcIdent, RdbcValue, RdbcValueIdent};
+use bmbp_rdbc_type::{RdbcColumnIdent, RdbcIdent, RdbcValue, RdbcValueIdent};
 
 pub trait RdbcGroupBuilder {
     fn group_mut(&mut self) -> &mut Vec<RdbcGroupColumn>;
@@ -54,7 +55,7 @@ pub trait RdbcGroupBuilder {
         T: RdbcValueIdent,
     {
         self.group_mut().push(RdbcGroupColumn {
-            column: RdbcColumn::RawColumn(RdbcRawColumn {
+            column: RdbcColumn::LiteralColumn(RdbcLiteralColumn {
                 column: value.value(),
             }),
         });
@@ -123,6 +124,13 @@ pub trait RdbcGroupBuilder {
         self
     }
 
+    fn group_by_case(&mut self, case: RdbcCaseColumn) -> &mut Self {
+        self.group_mut().push(RdbcGroupColumn {
+            column: RdbcColumn::CaseColumn(case),
+        });
+        self
+    }
+
     fn group_by_column(&mut self, column: RdbcGroupColumn) -> &mut Self {
         self.group_mut().push(column);
         self
@@ -140,4 +148,11 @@ pub trait RdbcGroupBuilder {
         });
         self
     }
+    /// 类型化列，渲染为 alias.column
+    fn group_by_ident<C>(&mut self, _column: C) -> &mut Self
+    where
+        C: RdbcColumnIdent,
+    {
+        self.group_by_rdbc_column(RdbcColumn::column_ident::<C>())
+    }
 }
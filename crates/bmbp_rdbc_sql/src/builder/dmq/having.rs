@@ -11,7 +11,6 @@ impl RdbcWhereFilterBuilder for RdbcHaving{
         self.filter.get_or_insert(RdbcWhereFilter{
             type_: RdbcFilterType::And,
             conditions: vec![],
-            distinct: false,
         })
     }
     fn filter_take(&mut self) -> Option<RdbcWhereFilter> {
@@ -0,0 +1,50 @@
+use crate::{RdbcLock, RdbcLockMode, RdbcLockWait};
+use bmbp_rdbc_type::RdbcIdent;
+
+/// 行锁构建，未指定锁模式时 OF、NOWAIT、SKIP LOCKED 默认使用 FOR UPDATE
+pub trait RdbcLockBuilder {
+    fn lock_mut(&mut self) -> &mut Option<RdbcLock>;
+    fn lock(&mut self, mode: RdbcLockMode) -> &mut Self {
+        lock_or_default(self.lock_mut()).mode = mode;
+        self
+    }
+    fn for_update(&mut self) -> &mut Self {
+        self.lock(RdbcLockMode::Update)
+    }
+    fn for_no_key_update(&mut self) -> &mut Self {
+        self.lock(RdbcLockMode::NoKeyUpdate)
+    }
+    fn for_share(&mut self) -> &mut Self {
+        self.lock(RdbcLockMode::Share)
+    }
+    fn for_key_share(&mut self) -> &mut Self {
+        self.lock(RdbcLockMode::KeyShare)
+    }
+    /// FOR UPDATE OF table，多次调用追加
+    fn lock_of<T>(&mut self, table: T) -> &mut Self
+    where
+        T: RdbcIdent,
+    {
+        lock_or_default(self.lock_mut()).of.push(table.name());
+        self
+    }
+    fn nowait(&mut self) -> &mut Self {
+        lock_or_default(self.lock_mut()).wait = RdbcLockWait::NoWait;
+        self
+    }
+    fn skip_locked(&mut self) -> &mut Self {
+        lock_or_default(self.lock_mut()).wait = RdbcLockWait::SkipLocked;
+        self
+    }
+    fn lock_take(&mut self) -> Option<RdbcLock> {
+        self.lock_mut().take()
+    }
+}
+
+fn lock_or_default(lock: &mut Option<RdbcLock>) -> &mut RdbcLock {
+    lock.get_or_insert(RdbcLock {
+        mode: RdbcLockMode::Update,
+        of: vec![],
+        wait: RdbcLockWait::Wait,
+    })
+}
@@ -7,5 +7,12 @@ pub mod order;
 pub mod func;
 pub mod group;
 pub mod having;
-mod union;
-mod limit;
+pub mod union;
+pub mod limit;
+pub mod with;
+pub mod window;
+pub mod case;
+pub mod expr;
+pub mod lock;
+pub mod distinct;
+pub mod returning;
@@ -1,4 +1,5 @@
-use crate::{RdbcColumn, RdbcOrderColumn, RdbcOrderType};
+use crate::{RdbcCaseColumn, RdbcColumn, RdbcOrderColumn, RdbcOrderType};
+use bmbp_rdbc_type::RdbcColumnIdent;
 
 pub trait RdbcOrderBuilder{
     fn order_mut(&mut self) -> &mut Vec<RdbcOrderColumn>;
@@ -13,6 +14,9 @@ pub trait RdbcOrderBuilder{
         self.order_mut().push(rdbc_order_column);
         self
     }
+    fn order_case(&mut self, case: RdbcCaseColumn, order: RdbcOrderType) -> &mut Self {
+        self.order(case, order)
+    }
     fn order_asc<C>(&mut self, column: C) -> &mut Self
     where
         RdbcColumn: From<C>,
@@ -44,5 +48,24 @@ pub trait RdbcOrderBuilder{
         }
         self
     }
+    /// 类型化列，渲染为 alias.column
+    fn order_ident<C>(&mut self, _column: C, order: RdbcOrderType) -> &mut Self
+    where
+        C: RdbcColumnIdent,
+    {
+        self.order(RdbcColumn::column_ident::<C>(), order)
+    }
+    fn order_ident_asc<C>(&mut self, column: C) -> &mut Self
+    where
+        C: RdbcColumnIdent,
+    {
+        self.order_ident(column, RdbcOrderType::Asc)
+    }
+    fn order_ident_desc<C>(&mut self, column: C) -> &mut Self
+    where
+        C: RdbcColumnIdent,
+    {
+        self.order_ident(column, RdbcOrderType::Desc)
+    }
 }
 
@@ -0,0 +1,27 @@
+use bmbp_rdbc_type::RdbcIdent;
+
+/// DML 语句的 RETURNING 列，返回受影响的行
+pub trait RdbcReturningBuilder {
+    fn returning_mut(&mut self) -> &mut Vec<String>;
+    fn returning<C>(&mut self, column: C) -> &mut Self
+    where
+        C: RdbcIdent,
+    {
+        self.returning_mut().push(column.name());
+        self
+    }
+    fn returning_vec<C>(&mut self, columns: Vec<C>) -> &mut Self
+    where
+        C: RdbcIdent,
+    {
+        for column in columns {
+            self.returning(column);
+        }
+        self
+    }
+    /// RETURNING *
+    fn returning_all(&mut self) -> &mut Self {
+        self.returning_mut().push("*".to_string());
+        self
+    }
+}
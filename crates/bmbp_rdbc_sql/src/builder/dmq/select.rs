@@ -1,8 +1,10 @@
 use crate::{
-    RdbcColumn, RdbcFunc, RdbcFuncColumn, RdbcQuery, RdbcQueryColumn, RdbcRawColumn,
-    RdbcSelectColumn, RdbcSimpleColumn, RdbcTableColumn, RdbcValueColumn,
+    RdbcCaseColumn, RdbcColumn, RdbcCountFunc, RdbcExprColumn, RdbcFunc, RdbcFuncColumn,
+    RdbcLiteralColumn, RdbcOffsetFunc, RdbcQuery, RdbcQueryColumn, RdbcRawColumn, RdbcSelectColumn,
+    RdbcSimpleColumn, RdbcSumFunc, RdbcSumFuncColumn, RdbcTableColumn, RdbcValueColumn, RdbcWindow,
+    RdbcWindowFunc, RdbcWindowFuncType,
 };
-use bmbp_rdbc_type::{RdbcIdent, RdbcValue, RdbcValueIdent};
+use bmbp_rdbc_type::{RdbcColumnIdent, RdbcIdent, RdbcValue, RdbcValueIdent};
 
 pub trait RdbcSelectBuilder {
     fn select_mut(&mut self) -> &mut Vec<RdbcSelectColumn>;
@@ -90,7 +92,7 @@ pub trait RdbcSelectBuilder {
         T: RdbcValueIdent,
     {
         self.select_mut().push(RdbcSelectColumn {
-            column: RdbcColumn::RawColumn(RdbcRawColumn {
+            column: RdbcColumn::LiteralColumn(RdbcLiteralColumn {
                 column: value.value(),
             }),
             alias: "".to_string(),
@@ -104,7 +106,7 @@ pub trait RdbcSelectBuilder {
         A: RdbcIdent,
     {
         self.select_mut().push(RdbcSelectColumn {
-            column: RdbcColumn::RawColumn(RdbcRawColumn {
+            column: RdbcColumn::LiteralColumn(RdbcLiteralColumn {
                 column: value.value(),
             }),
             alias: alias.name(),
@@ -201,6 +203,20 @@ pub trait RdbcSelectBuilder {
         });
         self
     }
+    /// 类型化列，如 select_ident(DictCode) 渲染为 alias.dict_code
+    fn select_ident<C>(&mut self, column: C) -> &mut Self
+    where
+        C: RdbcColumnIdent,
+    {
+        self.select_ident_as(column, "")
+    }
+    fn select_ident_as<C, A>(&mut self, _column: C, alias: A) -> &mut Self
+    where
+        C: RdbcColumnIdent,
+        A: RdbcIdent,
+    {
+        self.select_rdbc_column_as(RdbcColumn::column_ident::<C>(), alias)
+    }
     fn select_query_column(&mut self, column: RdbcQueryColumn) -> &mut Self {
         self.select_query_column_as(column, "")
     }
@@ -275,4 +291,144 @@ pub trait RdbcSelectBuilder {
     {
         self.select_func_column_as(RdbcFuncColumn { func }, alias)
     }
+
+    fn select_case(&mut self, case: RdbcCaseColumn) -> &mut Self {
+        self.select_mut().push(RdbcSelectColumn {
+            column: RdbcColumn::CaseColumn(case),
+            alias: "".to_string(),
+        });
+        self
+    }
+    fn select_case_as<A>(&mut self, case: RdbcCaseColumn, alias: A) -> &mut Self
+    where
+        A: RdbcIdent,
+    {
+        self.select_mut().push(RdbcSelectColumn {
+            column: RdbcColumn::CaseColumn(case),
+            alias: alias.name(),
+        });
+        self
+    }
+
+    fn select_expr(&mut self, expr: RdbcExprColumn) -> &mut Self {
+        self.select_mut().push(RdbcSelectColumn {
+            column: RdbcColumn::ExprColumn(expr),
+            alias: "".to_string(),
+        });
+        self
+    }
+    fn select_expr_as<A>(&mut self, expr: RdbcExprColumn, alias: A) -> &mut Self
+    where
+        A: RdbcIdent,
+    {
+        self.select_mut().push(RdbcSelectColumn {
+            column: RdbcColumn::ExprColumn(expr),
+            alias: alias.name(),
+        });
+        self
+    }
+
+    fn select_window_func_as<A>(
+        &mut self,
+        func: RdbcWindowFuncType,
+        over: RdbcWindow,
+        alias: A,
+    ) -> &mut Self
+    where
+        A: RdbcIdent,
+    {
+        self.select_func_as(RdbcFunc::WINDOW(RdbcWindowFunc { func, over }), alias)
+    }
+    fn select_row_number_as<A>(&mut self, over: RdbcWindow, alias: A) -> &mut Self
+    where
+        A: RdbcIdent,
+    {
+        self.select_window_func_as(RdbcWindowFuncType::RowNumber, over, alias)
+    }
+    fn select_rank_as<A>(&mut self, over: RdbcWindow, alias: A) -> &mut Self
+    where
+        A: RdbcIdent,
+    {
+        self.select_window_func_as(RdbcWindowFuncType::Rank, over, alias)
+    }
+    fn select_dense_rank_as<A>(&mut self, over: RdbcWindow, alias: A) -> &mut Self
+    where
+        A: RdbcIdent,
+    {
+        self.select_window_func_as(RdbcWindowFuncType::DenseRank, over, alias)
+    }
+    fn select_lag_as<C, A>(
+        &mut self,
+        column: C,
+        offset: u64,
+        over: RdbcWindow,
+        alias: A,
+    ) -> &mut Self
+    where
+        C: RdbcIdent,
+        A: RdbcIdent,
+    {
+        let func = RdbcOffsetFunc {
+            column: Box::new(simple_column(column)),
+            offset: Some(offset),
+            default: None,
+        };
+        self.select_window_func_as(RdbcWindowFuncType::Lag(func), over, alias)
+    }
+    fn select_lead_as<C, A>(
+        &mut self,
+        column: C,
+        offset: u64,
+        over: RdbcWindow,
+        alias: A,
+    ) -> &mut Self
+    where
+        C: RdbcIdent,
+        A: RdbcIdent,
+    {
+        let func = RdbcOffsetFunc {
+            column: Box::new(simple_column(column)),
+            offset: Some(offset),
+            default: None,
+        };
+        self.select_window_func_as(RdbcWindowFuncType::Lead(func), over, alias)
+    }
+    fn select_first_value_as<C, A>(&mut self, column: C, over: RdbcWindow, alias: A) -> &mut Self
+    where
+        C: RdbcIdent,
+        A: RdbcIdent,
+    {
+        let func = RdbcWindowFuncType::FirstValue(Box::new(simple_column(column)));
+        self.select_window_func_as(func, over, alias)
+    }
+    fn select_sum_over_as<C, A>(&mut self, column: C, over: RdbcWindow, alias: A) -> &mut Self
+    where
+        C: RdbcIdent,
+        A: RdbcIdent,
+    {
+        let func = RdbcFunc::SUM(RdbcSumFunc {
+            column: vec![RdbcSumFuncColumn::Column(simple_column(column))],
+        });
+        self.select_window_func_as(RdbcWindowFuncType::Aggregate(Box::new(func)), over, alias)
+    }
+    fn select_count_over_as<C, A>(&mut self, column: C, over: RdbcWindow, alias: A) -> &mut Self
+    where
+        C: RdbcIdent,
+        A: RdbcIdent,
+    {
+        let func = RdbcFunc::COUNT(RdbcCountFunc {
+            column: vec![simple_column(column)],
+            distinct: false,
+        });
+        self.select_window_func_as(RdbcWindowFuncType::Aggregate(Box::new(func)), over, alias)
+    }
+}
+
+fn simple_column<C>(column: C) -> RdbcColumn
+where
+    C: RdbcIdent,
+{
+    RdbcColumn::SimpleColumn(RdbcSimpleColumn {
+        column: column.name(),
+    })
 }
@@ -1,10 +1,14 @@
 use bmbp_rdbc_type::RdbcIdent;
 
 use crate::filter::RdbcWhereFilterBuilder;
-use crate::JoinType::{FullJoin, InnerJoin, LeftJoin, RightJoin};
+use crate::JoinType::{
+    CrossJoin, FullJoin, InnerJoin, LateralJoin, LeftJoin, LeftLateralJoin, NaturalJoin,
+    NaturalLeftJoin, RightJoin,
+};
 use crate::{
-    JoinType, RdbcFilterType, RdbcJoinTable, RdbcQuery, RdbcQueryTable, RdbcRawTable
-    , RdbcSimpleTable, RdbcTable, RdbcWhereFilter,
+    JoinType, RdbcColumn, RdbcCompare, RdbcFilterType, RdbcFilterValue, RdbcJoinTable, RdbcQuery,
+    RdbcQueryTable, RdbcRawTable, RdbcSimpleColumn, RdbcSimpleTable, RdbcTable,
+    RdbcWhereCondition, RdbcWhereFilter, RdbcWhereSimpleCondition,
 };
 
 pub trait RdbcTableBuilder {
@@ -393,6 +397,63 @@ pub trait RdbcJoinTableBuilder {
        self.with_join_rdbc_raw_table(table,FullJoin)
     }
 
+    fn cross_join_table<T>(&mut self, table: T) -> &mut RdbcJoinTable
+    where
+        T: RdbcIdent,
+    {
+        self.cross_join_table_as(table, "")
+    }
+    fn cross_join_table_as<T, A>(&mut self, table: T, alias: A) -> &mut RdbcJoinTable
+    where
+        T: RdbcIdent,
+        A: RdbcIdent,
+    {
+        self.with_join_schema_table_as("", table.name(), alias.name(), CrossJoin)
+    }
+    fn natural_join_table<T>(&mut self, table: T) -> &mut RdbcJoinTable
+    where
+        T: RdbcIdent,
+    {
+        self.natural_join_table_as(table, "")
+    }
+    fn natural_join_table_as<T, A>(&mut self, table: T, alias: A) -> &mut RdbcJoinTable
+    where
+        T: RdbcIdent,
+        A: RdbcIdent,
+    {
+        self.with_join_schema_table_as("", table.name(), alias.name(), NaturalJoin)
+    }
+    fn natural_left_join_table<T>(&mut self, table: T) -> &mut RdbcJoinTable
+    where
+        T: RdbcIdent,
+    {
+        self.natural_left_join_table_as(table, "")
+    }
+    fn natural_left_join_table_as<T, A>(&mut self, table: T, alias: A) -> &mut RdbcJoinTable
+    where
+        T: RdbcIdent,
+        A: RdbcIdent,
+    {
+        self.with_join_schema_table_as("", table.name(), alias.name(), NaturalLeftJoin)
+    }
+    /// JOIN LATERAL (query) alias，子查询可引用前面的表
+    fn lateral_join_table_query_as<A>(&mut self, query: RdbcQuery, alias: A) -> &mut RdbcJoinTable
+    where
+        A: RdbcIdent,
+    {
+        self.with_join_table_query_as(query, alias.name(), LateralJoin)
+    }
+    fn left_lateral_join_table_query_as<A>(
+        &mut self,
+        query: RdbcQuery,
+        alias: A,
+    ) -> &mut RdbcJoinTable
+    where
+        A: RdbcIdent,
+    {
+        self.with_join_table_query_as(query, alias.name(), LeftLateralJoin)
+    }
+
     fn with_join_table<T, A>(&mut self, table: T, join_type: JoinType) -> &mut RdbcJoinTable
     where
         T: RdbcIdent,
@@ -444,6 +505,7 @@ pub trait RdbcJoinTableBuilder {
             }),
             join_type,
             filter: None,
+            using: vec![],
         };
         self.table_join_mut().push(table);
         self.table_join_mut().last_mut().unwrap()
@@ -472,6 +534,7 @@ pub trait RdbcJoinTableBuilder {
             }),
             join_type,
             filter: None,
+            using: vec![],
         };
         self.table_join_mut().push(table);
         self.table_join_mut().last_mut().unwrap()
@@ -500,6 +563,7 @@ pub trait RdbcJoinTableBuilder {
             }),
             join_type,
             filter: None,
+            using: vec![],
         };
         self.table_join_mut().push(table);
         self.table_join_mut().last_mut().unwrap()
@@ -513,6 +577,7 @@ pub trait RdbcJoinTableBuilder {
             table,
             join_type,
             filter: None,
+            using: vec![],
         };
         self.table_join_mut().push(table);
         self.table_join_mut().last_mut().unwrap()
@@ -526,6 +591,7 @@ pub trait RdbcJoinTableBuilder {
             table: RdbcTable::SimpleTable(table),
             join_type,
             filter: None,
+            using: vec![],
         };
         self.table_join_mut().push(table);
         self.table_join_mut().last_mut().unwrap()
@@ -539,6 +605,7 @@ pub trait RdbcJoinTableBuilder {
             table: RdbcTable::QueryTable(query),
             join_type,
             filter: None,
+            using: vec![],
         };
         self.table_join_mut().push(table);
         self.table_join_mut().last_mut().unwrap()
@@ -552,6 +619,7 @@ pub trait RdbcJoinTableBuilder {
             table: RdbcTable::RawTable(table),
             join_type,
             filter: None,
+            using: vec![],
         };
         self.table_join_mut().push(table);
         self.table_join_mut().last_mut().unwrap()
@@ -563,10 +631,55 @@ impl RdbcWhereFilterBuilder for RdbcJoinTable {
         self.filter.get_or_insert(RdbcWhereFilter {
             type_: RdbcFilterType::And,
             conditions: vec![],
-            distinct: false,
         })
     }
     fn filter_take(&mut self) -> Option<RdbcWhereFilter> {
         self.filter.take()
     }
 }
+
+impl RdbcJoinTable {
+    /// ON left = right，两侧均为列
+    pub fn on_eq<L, R>(&mut self, left: L, right: R) -> &mut Self
+    where
+        L: RdbcIdent,
+        R: RdbcIdent,
+    {
+        self.on_col(left, RdbcCompare::EQ, right)
+    }
+    /// ON left compare right，两侧均为列
+    pub fn on_col<L, R>(&mut self, left: L, compare: RdbcCompare, right: R) -> &mut Self
+    where
+        L: RdbcIdent,
+        R: RdbcIdent,
+    {
+        let condition = RdbcWhereCondition::Simple(RdbcWhereSimpleCondition {
+            column: RdbcColumn::SimpleColumn(RdbcSimpleColumn {
+                column: left.name(),
+            }),
+            compare,
+            value: RdbcFilterValue::Column(RdbcColumn::SimpleColumn(RdbcSimpleColumn {
+                column: right.name(),
+            })),
+        });
+        self.filter_mut().conditions.push(condition);
+        self
+    }
+    /// USING (column)，多次调用追加
+    pub fn using<C>(&mut self, column: C) -> &mut Self
+    where
+        C: RdbcIdent,
+    {
+        self.using.push(column.name());
+        self
+    }
+    pub fn using_vec<C>(&mut self, columns: Vec<C>) -> &mut Self
+    where
+        C: RdbcIdent,
+    {
+        for column in columns {
+            self.using(column);
+        }
+        self
+    }
+}
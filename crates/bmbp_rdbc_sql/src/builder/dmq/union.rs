@@ -1,8 +1,66 @@
-use crate::RdbcQuery;
+use crate::{
+    RdbcColumn, RdbcCompoundQuery, RdbcCompoundType, RdbcOrderColumn, RdbcOrderType, RdbcQuery,
+};
 
-pub trait RdbcUnionBuilder{
-    fn union(&mut self, query: RdbcQuery) -> &mut Self;
+/// 集合查询构建，按调用顺序拼接 UNION、INTERSECT、EXCEPT
+pub trait RdbcCompoundBuilder {
+    fn compound_mut(&mut self) -> &mut Vec<RdbcCompoundQuery>;
+    /// 作用于整个集合查询的排序
+    fn compound_order_mut(&mut self) -> &mut Vec<RdbcOrderColumn>;
+    /// 作用于整个集合查询的分页
+    fn compound_limit(&mut self, limit: u64) -> &mut Self;
+    fn compound_offset(&mut self, offset: u64) -> &mut Self;
+
+    fn compound(&mut self, compound_type: RdbcCompoundType, query: RdbcQuery) -> &mut Self {
+        self.compound_mut().push(RdbcCompoundQuery {
+            compound_type,
+            query,
+        });
+        self
+    }
+    fn intersect(&mut self, query: RdbcQuery) -> &mut Self {
+        self.compound(RdbcCompoundType::Intersect, query)
+    }
+    fn intersect_all(&mut self, query: RdbcQuery) -> &mut Self {
+        self.compound(RdbcCompoundType::IntersectAll, query)
+    }
+    fn except(&mut self, query: RdbcQuery) -> &mut Self {
+        self.compound(RdbcCompoundType::Except, query)
+    }
+    fn except_all(&mut self, query: RdbcQuery) -> &mut Self {
+        self.compound(RdbcCompoundType::ExceptAll, query)
+    }
+    fn compound_order<C>(&mut self, column: C, order: RdbcOrderType) -> &mut Self
+    where
+        RdbcColumn: From<C>,
+    {
+        self.compound_order_mut().push(RdbcOrderColumn {
+            column: RdbcColumn::from(column),
+            order_type: order,
+        });
+        self
+    }
+    fn compound_order_asc<C>(&mut self, column: C) -> &mut Self
+    where
+        RdbcColumn: From<C>,
+    {
+        self.compound_order(column, RdbcOrderType::Asc)
+    }
+    fn compound_order_desc<C>(&mut self, column: C) -> &mut Self
+    where
+        RdbcColumn: From<C>,
+    {
+        self.compound_order(column, RdbcOrderType::Desc)
+    }
+}
+
+pub trait RdbcUnionBuilder: RdbcCompoundBuilder {
+    fn union(&mut self, query: RdbcQuery) -> &mut Self {
+        self.compound(RdbcCompoundType::Union, query)
+    }
+}
+pub trait RdbcUnionAllBuilder: RdbcCompoundBuilder {
+    fn union_all(&mut self, query: RdbcQuery) -> &mut Self {
+        self.compound(RdbcCompoundType::UnionAll, query)
+    }
 }
-pub trait RdbcUnionAllBuilder{
-    fn union_all(&mut self, query: RdbcQuery) -> &mut Self;
-}
\ No newline at end of file
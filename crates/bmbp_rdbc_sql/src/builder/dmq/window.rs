@@ -0,0 +1,88 @@
+use crate::{
+    RdbcColumn, RdbcFrameBound, RdbcFrameUnit, RdbcOrderColumn, RdbcOrderType, RdbcSimpleColumn,
+    RdbcWindow, RdbcWindowFrame,
+};
+use bmbp_rdbc_type::RdbcIdent;
+
+/// 窗口定义构建器，生成 OVER (PARTITION BY ... ORDER BY ... ROWS ...)
+pub struct RdbcWindowBuilder {
+    window: RdbcWindow,
+}
+
+impl Default for RdbcWindowBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RdbcWindowBuilder {
+    pub fn new() -> Self {
+        RdbcWindowBuilder {
+            window: RdbcWindow::default(),
+        }
+    }
+    pub fn build(&self) -> RdbcWindow {
+        self.window.clone()
+    }
+    pub fn partition_by<C>(&mut self, column: C) -> &mut Self
+    where
+        C: RdbcIdent,
+    {
+        self.partition_by_column(RdbcColumn::SimpleColumn(RdbcSimpleColumn {
+            column: column.name(),
+        }))
+    }
+    pub fn partition_by_column(&mut self, column: RdbcColumn) -> &mut Self {
+        self.window.partition_by.push(column);
+        self
+    }
+    pub fn order_asc<C>(&mut self, column: C) -> &mut Self
+    where
+        C: RdbcIdent,
+    {
+        self.order_column(
+            RdbcColumn::SimpleColumn(RdbcSimpleColumn {
+                column: column.name(),
+            }),
+            RdbcOrderType::Asc,
+        )
+    }
+    pub fn order_desc<C>(&mut self, column: C) -> &mut Self
+    where
+        C: RdbcIdent,
+    {
+        self.order_column(
+            RdbcColumn::SimpleColumn(RdbcSimpleColumn {
+                column: column.name(),
+            }),
+            RdbcOrderType::Desc,
+        )
+    }
+    pub fn order_column(&mut self, column: RdbcColumn, order_type: RdbcOrderType) -> &mut Self {
+        self.window
+            .order_by
+            .push(RdbcOrderColumn { column, order_type });
+        self
+    }
+    pub fn rows(&mut self, start: RdbcFrameBound) -> &mut Self {
+        self.frame(RdbcFrameUnit::Rows, start, None)
+    }
+    pub fn rows_between(&mut self, start: RdbcFrameBound, end: RdbcFrameBound) -> &mut Self {
+        self.frame(RdbcFrameUnit::Rows, start, Some(end))
+    }
+    pub fn range(&mut self, start: RdbcFrameBound) -> &mut Self {
+        self.frame(RdbcFrameUnit::Range, start, None)
+    }
+    pub fn range_between(&mut self, start: RdbcFrameBound, end: RdbcFrameBound) -> &mut Self {
+        self.frame(RdbcFrameUnit::Range, start, Some(end))
+    }
+    fn frame(
+        &mut self,
+        unit: RdbcFrameUnit,
+        start: RdbcFrameBound,
+        end: Option<RdbcFrameBound>,
+    ) -> &mut Self {
+        self.window.frame = Some(RdbcWindowFrame { unit, start, end });
+        self
+    }
+}
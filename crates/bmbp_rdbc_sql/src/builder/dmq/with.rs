@@ -0,0 +1,47 @@
+use crate::{RdbcQuery, RdbcWithTable};
+use bmbp_rdbc_type::RdbcIdent;
+
+pub trait RdbcWithBuilder {
+    fn with_mut(&mut self) -> &mut Vec<RdbcWithTable>;
+
+    fn with<N>(&mut self, name: N, query: RdbcQuery) -> &mut Self
+    where
+        N: RdbcIdent,
+    {
+        self.with_columns::<N, String>(name, vec![], query)
+    }
+    fn with_columns<N, C>(&mut self, name: N, columns: Vec<C>, query: RdbcQuery) -> &mut Self
+    where
+        N: RdbcIdent,
+        C: RdbcIdent,
+    {
+        self.with_table(name, columns, query, false)
+    }
+    /// 递归查询，query 通常为 锚点查询 UNION ALL 递归查询
+    fn with_recursive<N, C>(&mut self, name: N, columns: Vec<C>, query: RdbcQuery) -> &mut Self
+    where
+        N: RdbcIdent,
+        C: RdbcIdent,
+    {
+        self.with_table(name, columns, query, true)
+    }
+    fn with_table<N, C>(
+        &mut self,
+        name: N,
+        columns: Vec<C>,
+        query: RdbcQuery,
+        recursive: bool,
+    ) -> &mut Self
+    where
+        N: RdbcIdent,
+        C: RdbcIdent,
+    {
+        self.with_mut().push(RdbcWithTable {
+            name: name.name(),
+            columns: columns.iter().map(|column| column.name()).collect(),
+            query,
+            recursive,
+        });
+        self
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
-use bmbp_rdbc_type::RdbcValue;
-use crate::define::dmq::{RdbcFunc, RdbcQuery, RdbcTable};
+use bmbp_rdbc_type::{RdbcColumnIdent, RdbcValue};
+use crate::define::dmq::{RdbcFunc, RdbcQuery, RdbcSimpleTable, RdbcTable, RdbcWhereFilter};
 
 #[derive(Debug, Clone,Serialize,Deserialize)]
 pub enum RdbcColumn {
@@ -10,6 +10,9 @@ pub enum RdbcColumn {
     ValueColumn(RdbcValueColumn),
     FuncColumn(RdbcFuncColumn),
     RawColumn(RdbcRawColumn),
+    LiteralColumn(RdbcLiteralColumn),
+    CaseColumn(RdbcCaseColumn),
+    ExprColumn(RdbcExprColumn),
 }
 #[derive(Debug, Clone,Serialize,Deserialize)]
 pub struct RdbcSimpleColumn {
@@ -37,4 +40,115 @@ pub struct RdbcFuncColumn {
 pub struct RdbcRawColumn {
     pub column: String,
 }
+/// 字面量列，渲染时按方言转义后直接写入 SQL
+#[derive(Debug, Clone,Serialize,Deserialize)]
+pub struct RdbcLiteralColumn {
+    pub column: RdbcValue,
+}
+
+/// CASE 表达式，operand 为空时为 CASE WHEN 条件 THEN ...，否则为 CASE operand WHEN 值 THEN ...
+#[derive(Debug, Clone,Serialize,Deserialize)]
+pub struct RdbcCaseColumn {
+    pub operand: Option<Box<RdbcColumn>>,
+    pub when: Vec<RdbcCaseWhen>,
+    pub else_: Option<Box<RdbcColumn>>,
+}
+/// WHEN 分支，条件式 CASE 使用 filter，简单 CASE 使用 value
+#[derive(Debug, Clone,Serialize,Deserialize)]
+pub struct RdbcCaseWhen {
+    pub filter: Option<RdbcWhereFilter>,
+    pub value: Option<RdbcColumn>,
+    pub then: RdbcColumn,
+}
+/// 运算表达式，可嵌套任意 RdbcColumn
+#[derive(Debug, Clone,Serialize,Deserialize)]
+pub enum RdbcExprColumn {
+    Binary(RdbcBinaryExpr),
+    /// 取负 -x
+    Negative(Box<RdbcColumn>),
+    /// CAST(x AS type)
+    Cast(RdbcCastExpr),
+}
+#[derive(Debug, Clone,Serialize,Deserialize)]
+pub struct RdbcBinaryExpr {
+    pub left: Box<RdbcColumn>,
+    pub operator: RdbcOperator,
+    pub right: Box<RdbcColumn>,
+}
+#[derive(Debug, Clone,Serialize,Deserialize)]
+pub enum RdbcOperator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Mod,
+    /// 字符串拼接 ||
+    Concat,
+}
+#[derive(Debug, Clone,Serialize,Deserialize)]
+pub struct RdbcCastExpr {
+    pub column: Box<RdbcColumn>,
+    pub type_: String,
+}
 
+impl RdbcColumn {
+    /// 由类型化列标识构造，有表名或表别名时为表列
+    pub fn column_ident<C>() -> Self
+    where
+        C: RdbcColumnIdent,
+    {
+        let table = C::table_name();
+        let alias = C::table_alias();
+        if table.is_empty() && alias.is_empty() {
+            return RdbcColumn::SimpleColumn(RdbcSimpleColumn {
+                column: C::column_name(),
+            });
+        }
+        RdbcColumn::TableColumn(RdbcTableColumn {
+            table: RdbcTable::SimpleTable(RdbcSimpleTable {
+                schema: "".to_string(),
+                table,
+                alias,
+            }),
+            column: C::column_name(),
+        })
+    }
+}
+impl From<&str> for RdbcColumn {
+    fn from(column: &str) -> Self {
+        RdbcColumn::SimpleColumn(RdbcSimpleColumn {
+            column: column.to_string(),
+        })
+    }
+}
+impl From<String> for RdbcColumn {
+    fn from(column: String) -> Self {
+        RdbcColumn::SimpleColumn(RdbcSimpleColumn { column })
+    }
+}
+impl From<&String> for RdbcColumn {
+    fn from(column: &String) -> Self {
+        RdbcColumn::from(column.as_str())
+    }
+}
+/// 值作为绑定参数
+impl From<RdbcValue> for RdbcColumn {
+    fn from(column: RdbcValue) -> Self {
+        RdbcColumn::ValueColumn(RdbcValueColumn { column })
+    }
+}
+impl From<RdbcCaseColumn> for RdbcColumn {
+    fn from(column: RdbcCaseColumn) -> Self {
+        RdbcColumn::CaseColumn(column)
+    }
+}
+impl From<RdbcExprColumn> for RdbcColumn {
+    fn from(column: RdbcExprColumn) -> Self {
+        RdbcColumn::ExprColumn(column)
+    }
+}
+impl From<RdbcFunc> for RdbcColumn {
+    fn from(func: RdbcFunc) -> Self {
+        RdbcColumn::FuncColumn(RdbcFuncColumn { func })
+    }
+}
@@ -7,9 +7,14 @@ use crate::define::dmq::{RdbcColumn, RdbcFunc, RdbcJoinTable, RdbcOrderColumn, R
 pub struct RdbcInsert {
     pub(crate) table: Vec<RdbcTable>,
     pub(crate) column: Vec<String>,
-    pub(crate) values: Vec<RdbcDmlValue>,
+    pub(crate) values: Vec<Vec<RdbcDmlValue>>,
     pub(crate) column_value: Vec<RdbcDmlColumn>,
     pub(crate) query: Option<RdbcQuery>,
+    /// 冲突处理，未设置时为普通插入
+    pub(crate) conflict: Option<RdbcConflict>,
+    pub(crate) returning: Vec<String>,
+    /// 主键列，数据库不支持 RETURNING 时按主键回查插入行
+    pub(crate) returning_key: Option<String>,
 }
 #[derive(Debug, Clone,  Serialize, Deserialize)]
 pub struct RdbcUpdate {
@@ -20,6 +25,7 @@ pub struct RdbcUpdate {
     pub(crate) order_by: Vec<RdbcOrderColumn>,
     pub(crate) limit: Option<u64>,
     pub(crate) offset: Option<u64>,
+    pub(crate) returning: Vec<String>,
 }
 #[derive(Debug, Clone,  Serialize, Deserialize)]
 pub struct RdbcDelete {
@@ -29,6 +35,9 @@ pub struct RdbcDelete {
     pub(crate) order_by: Vec<RdbcOrderColumn>,
     pub(crate) limit: Option<u64>,
     pub(crate) offset: Option<u64>,
+    /// 是否允许无过滤条件删除全表
+    pub(crate) allow_delete_all: bool,
+    pub(crate) returning: Vec<String>,
 }
 
 #[derive(Debug, Clone,  Serialize, Deserialize)]
@@ -43,3 +52,32 @@ pub enum RdbcDmlValue {
     FUNC(RdbcFunc),
 }
 
+/// 插入冲突处理，即 UPSERT
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RdbcConflict {
+    pub(crate) target: Option<RdbcConflictTarget>,
+    pub(crate) action: RdbcConflictAction,
+}
+/// 冲突目标，唯一键列或约束名
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum RdbcConflictTarget {
+    Columns(Vec<String>),
+    Constraint(String),
+}
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum RdbcConflictAction {
+    Nothing,
+    Update(Vec<RdbcConflictColumn>),
+}
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RdbcConflictColumn {
+    pub column: String,
+    pub value: RdbcConflictValue,
+}
+/// 冲突更新值，Excluded 引用本次待插入行的同名列
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum RdbcConflictValue {
+    Excluded(String),
+    Value(Box<RdbcDmlValue>),
+}
+
@@ -1,9 +1,15 @@
 use serde::{Deserialize, Serialize};
-use crate::define::dmq::{RdbcGroupColumn, RdbcJoinTable, RdbcOrderColumn, RdbcSelectColumn, RdbcTable, RdbcWhereFilter};
+use crate::define::dmq::{RdbcColumn, RdbcGroupColumn, RdbcJoinTable, RdbcOrderColumn, RdbcSelectColumn, RdbcTable, RdbcWhereFilter};
 use crate::define::dmq::having::RdbcHaving;
+use crate::define::dmq::{RdbcLock, RdbcWithTable};
 
 #[derive(Debug, Clone,Serialize,Deserialize)]
 pub struct RdbcQuery {
+    pub(crate) with: Vec<RdbcWithTable>,
+    /// SELECT DISTINCT
+    pub(crate) distinct: bool,
+    /// SELECT DISTINCT ON (columns)，不支持的数据库使用 ROW_NUMBER 改写
+    pub(crate) distinct_on: Vec<RdbcColumn>,
     pub(crate) select: Vec<RdbcSelectColumn>,
     pub(crate) table: Vec<RdbcTable>,
     pub(crate) join_table: Vec<RdbcJoinTable>,
@@ -13,6 +19,26 @@ pub struct RdbcQuery {
     pub(crate) having: Option<RdbcHaving>,
     pub(crate) limit: Option<u64>,
     pub(crate) offset: Option<u64>,
-    pub(crate) union: Vec<RdbcQuery>,
-    pub(crate) union_all: Vec<RdbcQuery>,
+    /// 按顺序拼接的集合查询
+    pub(crate) compound: Vec<RdbcCompoundQuery>,
+    /// 作用于整个集合查询的排序与分页
+    pub(crate) compound_order_by: Vec<RdbcOrderColumn>,
+    pub(crate) compound_limit: Option<u64>,
+    pub(crate) compound_offset: Option<u64>,
+    pub(crate) lock: Option<RdbcLock>,
+}
+
+#[derive(Debug, Clone,Serialize,Deserialize)]
+pub struct RdbcCompoundQuery {
+    pub(crate) compound_type: RdbcCompoundType,
+    pub(crate) query: RdbcQuery,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RdbcCompoundType {
+    Union,
+    UnionAll,
+    Intersect,
+    IntersectAll,
+    Except,
+    ExceptAll,
 }
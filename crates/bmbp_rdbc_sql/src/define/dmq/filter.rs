@@ -6,7 +6,6 @@ use crate::define::dmq::{RdbcColumn, RdbcQuery};
 pub struct RdbcWhereFilter {
     pub type_: RdbcFilterType,
     pub conditions: Vec<RdbcWhereCondition>,
-    pub distinct: bool,
 }
 
 #[derive(Debug, Clone,Serialize,Deserialize)]
@@ -36,17 +35,23 @@ pub enum RdbcCompare {
     LT,
     LE,
     Like,
+    /// 左模糊，匹配以值结尾：'%' || 值
     LikeLeft,
+    /// 右模糊，匹配以值开头：值 || '%'
     LikeRight,
     NotLike,
     NotLikeLeft,
     NotLikeRight,
+    /// 空数组渲染为恒假条件
     IN,
+    /// 空数组渲染为恒真条件
     NotIn,
+    /// 仅使用子查询，忽略左侧列
     Exists,
     NotExits,
     IsNull,
     IsNotNull,
+    /// 值为两个元素的数组
     Between,
     NotBetween,
 }
@@ -67,4 +72,30 @@ pub enum RdbcFilterValue {
     Query(RdbcQuery),
     Script(String),
     Raw(String),
-}
\ No newline at end of file
+}
+/// 可选过滤值的跳过规则，与 RdbcOrmCurd 的 insert、insert_ignore_* 取值规则一致
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RdbcValueSkip {
+    /// 值为 None 时跳过，对应 insert_ignore_empty
+    None,
+    /// 值为空串或空数组时跳过，对应 insert_ignore_none
+    Empty,
+    /// 两者均跳过，对应 insert
+    Both,
+}
+
+impl RdbcValueSkip {
+    pub fn is_skip(&self, value: &RdbcValue) -> bool {
+        let is_none = value.is_null();
+        let is_empty = match value {
+            RdbcValue::Varchar(v) | RdbcValue::Text(v) | RdbcValue::LongText(v) => v.is_empty(),
+            RdbcValue::Array(v) => v.is_empty(),
+            _ => false,
+        };
+        match self {
+            RdbcValueSkip::None => is_none,
+            RdbcValueSkip::Empty => is_empty,
+            RdbcValueSkip::Both => is_none || is_empty,
+        }
+    }
+}
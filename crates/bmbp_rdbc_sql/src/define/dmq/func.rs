@@ -1,16 +1,44 @@
 use serde::{Deserialize, Serialize};
 use bmbp_rdbc_type::RdbcValue;
-use crate::RdbcColumn;
+use crate::{RdbcColumn, RdbcOrderColumn};
 
 #[derive(Debug, Clone,Serialize,Deserialize)]
 pub enum RdbcFunc {
     DISTINCT(RdbcDistinctFunc),
     ABS(RdbcAbsFunc),
     SUM(RdbcSumFunc),
+    COUNT(RdbcCountFunc),
+    MIN(RdbcColumnFunc),
+    MAX(RdbcColumnFunc),
+    AVG(RdbcColumnFunc),
+    /// 字符串聚合，按方言渲染为 STRING_AGG、GROUP_CONCAT 或 LISTAGG
+    StringAgg(RdbcStringAggFunc),
+    COALESCE(RdbcArgsFunc),
+    NULLIF(RdbcArgsFunc),
+    /// 字符串拼接，按方言渲染为 || 或 CONCAT
+    CONCAT(RdbcArgsFunc),
+    UPPER(RdbcColumnFunc),
+    LOWER(RdbcColumnFunc),
+    TRIM(RdbcColumnFunc),
+    SUBSTRING(RdbcSubstringFunc),
+    /// 字符长度
+    LENGTH(RdbcColumnFunc),
+    REPLACE(RdbcArgsFunc),
+    /// 当前时间戳，渲染为 CURRENT_TIMESTAMP
+    NOW,
+    CurrentDate,
+    DateTrunc(RdbcDateFunc),
+    EXTRACT(RdbcDateFunc),
+    /// 日期加减，amount 为负数时表示减
+    DateAdd(RdbcDateAddFunc),
+    ROUND(RdbcRoundFunc),
+    FLOOR(RdbcColumnFunc),
+    CEIL(RdbcColumnFunc),
+    WINDOW(RdbcWindowFunc),
 }
 #[derive(Debug, Clone,Serialize,Deserialize)]
 pub struct RdbcDistinctFunc {
-    columns: Vec<RdbcColumn>,
+    pub columns: Vec<RdbcColumn>,
 }
 
 
@@ -26,5 +54,112 @@ pub enum RdbcSumFuncColumn {
 }
 #[derive(Debug, Clone,Serialize,Deserialize)]
 pub struct RdbcAbsFunc {
-    column: Vec<RdbcColumn>,
+    pub column: Vec<RdbcColumn>,
+}
+/// COUNT 函数，无列时渲染为 COUNT(*)
+#[derive(Debug, Clone,Serialize,Deserialize)]
+pub struct RdbcCountFunc {
+    pub column: Vec<RdbcColumn>,
+    pub distinct: bool,
+}
+/// 单个参数的函数
+#[derive(Debug, Clone,Serialize,Deserialize)]
+pub struct RdbcColumnFunc {
+    pub column: Box<RdbcColumn>,
+}
+/// 多个参数的函数，参数按顺序渲染
+#[derive(Debug, Clone,Serialize,Deserialize)]
+pub struct RdbcArgsFunc {
+    pub args: Vec<RdbcColumn>,
+}
+#[derive(Debug, Clone,Serialize,Deserialize)]
+pub struct RdbcStringAggFunc {
+    pub column: Box<RdbcColumn>,
+    pub separator: String,
+}
+/// 截取字符串，start 从 1 开始
+#[derive(Debug, Clone,Serialize,Deserialize)]
+pub struct RdbcSubstringFunc {
+    pub column: Box<RdbcColumn>,
+    pub start: i64,
+    pub length: Option<i64>,
+}
+#[derive(Debug, Clone,Serialize,Deserialize)]
+pub enum RdbcDateUnit {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+/// DATE_TRUNC、EXTRACT 的参数
+#[derive(Debug, Clone,Serialize,Deserialize)]
+pub struct RdbcDateFunc {
+    pub unit: RdbcDateUnit,
+    pub column: Box<RdbcColumn>,
+}
+#[derive(Debug, Clone,Serialize,Deserialize)]
+pub struct RdbcDateAddFunc {
+    pub column: Box<RdbcColumn>,
+    pub amount: i64,
+    pub unit: RdbcDateUnit,
+}
+#[derive(Debug, Clone,Serialize,Deserialize)]
+pub struct RdbcRoundFunc {
+    pub column: Box<RdbcColumn>,
+    pub scale: Option<i32>,
+}
+
+/// 窗口函数，func OVER (window)
+#[derive(Debug, Clone,Serialize,Deserialize)]
+pub struct RdbcWindowFunc {
+    pub func: RdbcWindowFuncType,
+    pub over: RdbcWindow,
+}
+#[derive(Debug, Clone,Serialize,Deserialize)]
+pub enum RdbcWindowFuncType {
+    RowNumber,
+    Rank,
+    DenseRank,
+    Lag(RdbcOffsetFunc),
+    Lead(RdbcOffsetFunc),
+    FirstValue(Box<RdbcColumn>),
+    LastValue(Box<RdbcColumn>),
+    /// 聚合函数作为窗口函数，如累计 SUM、COUNT
+    Aggregate(Box<RdbcFunc>),
+}
+/// LAG、LEAD 的参数
+#[derive(Debug, Clone,Serialize,Deserialize)]
+pub struct RdbcOffsetFunc {
+    pub column: Box<RdbcColumn>,
+    pub offset: Option<u64>,
+    pub default: Option<RdbcValue>,
+}
+/// 窗口定义，OVER 后括号中的内容
+#[derive(Debug, Clone, Default,Serialize,Deserialize)]
+pub struct RdbcWindow {
+    pub partition_by: Vec<RdbcColumn>,
+    pub order_by: Vec<RdbcOrderColumn>,
+    pub frame: Option<RdbcWindowFrame>,
+}
+/// 窗口框架，无结束边界时渲染为 ROWS start
+#[derive(Debug, Clone,Serialize,Deserialize)]
+pub struct RdbcWindowFrame {
+    pub unit: RdbcFrameUnit,
+    pub start: RdbcFrameBound,
+    pub end: Option<RdbcFrameBound>,
+}
+#[derive(Debug, Clone,Serialize,Deserialize)]
+pub enum RdbcFrameUnit {
+    Rows,
+    Range,
+}
+#[derive(Debug, Clone,Serialize,Deserialize)]
+pub enum RdbcFrameBound {
+    UnboundedPreceding,
+    Preceding(u64),
+    CurrentRow,
+    Following(u64),
+    UnboundedFollowing,
 }
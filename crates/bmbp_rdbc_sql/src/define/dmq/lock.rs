@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/// 行锁，渲染为 FOR UPDATE [OF table] [NOWAIT | SKIP LOCKED]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RdbcLock {
+    pub(crate) mode: RdbcLockMode,
+    /// 仅锁定指定表的行
+    pub(crate) of: Vec<String>,
+    pub(crate) wait: RdbcLockWait,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RdbcLockMode {
+    /// FOR UPDATE
+    Update,
+    /// FOR NO KEY UPDATE
+    NoKeyUpdate,
+    /// FOR SHARE
+    Share,
+    /// FOR KEY SHARE
+    KeyShare,
+}
+
+/// 行已被锁定时的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RdbcLockWait {
+    /// 等待锁释放
+    Wait,
+    /// NOWAIT，立即报错
+    NoWait,
+    /// SKIP LOCKED，跳过已锁定的行
+    SkipLocked,
+}
@@ -8,6 +8,8 @@ mod order;
 mod select;
 mod table;
 mod having;
+mod with;
+mod lock;
 
 pub use base::*;
 pub use dml::*;
@@ -19,3 +21,5 @@ pub use order::*;
 pub use select::*;
 pub use table::*;
 pub use having::*;
+pub use with::*;
+pub use lock::*;
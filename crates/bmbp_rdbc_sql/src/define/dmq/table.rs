@@ -28,6 +28,8 @@ pub struct RdbcJoinTable {
     pub table: RdbcTable,
     pub join_type: JoinType,
     pub filter: Option<RdbcWhereFilter>,
+    /// JOIN ... USING (columns)，与 ON 条件互斥
+    pub using: Vec<String>,
 }
 #[derive(Debug, Clone,Serialize,Deserialize)]
 pub enum JoinType {
@@ -35,4 +37,13 @@ pub enum JoinType {
     LeftJoin,
     RightJoin,
     FullJoin,
+    /// CROSS JOIN，不带连接条件
+    CrossJoin,
+    /// NATURAL JOIN，按同名列连接
+    NaturalJoin,
+    NaturalLeftJoin,
+    /// 无连接条件时为 CROSS JOIN LATERAL，否则为 INNER JOIN LATERAL
+    LateralJoin,
+    /// LEFT JOIN LATERAL，无连接条件时使用 ON 1 = 1
+    LeftLateralJoin,
 }
@@ -0,0 +1,12 @@
+use crate::RdbcQuery;
+use serde::{Deserialize, Serialize};
+
+/// 公共表表达式，WITH name (columns) AS (query)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RdbcWithTable {
+    pub(crate) name: String,
+    pub(crate) columns: Vec<String>,
+    pub(crate) query: RdbcQuery,
+    /// 递归查询，渲染为 WITH RECURSIVE
+    pub(crate) recursive: bool,
+}
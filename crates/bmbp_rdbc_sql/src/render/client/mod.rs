@@ -0,0 +1,622 @@
+mod mysql;
+mod oracle;
+mod pg;
+mod sqlite;
+
+pub use mysql::*;
+pub use oracle::*;
+pub use pg::*;
+pub use sqlite::*;
+
+use crate::{RdbcCompoundType, RdbcDateUnit, RdbcLockMode, RdbcLockWait};
+use bmbp_rdbc_type::{RdbcErrKind, RdbcError, RdbcValue};
+
+/// 关联删除的语法形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RdbcDeleteJoinStyle {
+    /// DELETE FROM t USING j WHERE ...
+    Using,
+    /// DELETE t FROM t JOIN j ON ...
+    Join,
+    /// 不支持关联删除
+    Unsupported,
+}
+
+/// 关联更新的语法形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RdbcUpdateJoinStyle {
+    /// UPDATE t SET ... FROM j WHERE ...
+    From,
+    /// UPDATE t JOIN j ON ... SET ...
+    Join,
+    /// 不支持关联更新
+    Unsupported,
+}
+
+/// 插入冲突处理的语法形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RdbcUpsertStyle {
+    /// INSERT ... ON CONFLICT ... DO NOTHING | DO UPDATE SET ...
+    OnConflict,
+    /// INSERT ... ON DUPLICATE KEY UPDATE ...
+    OnDuplicateKey,
+    /// MERGE INTO t USING (...) s ON (...) WHEN MATCHED ... WHEN NOT MATCHED ...
+    Merge,
+    /// 不支持插入冲突处理
+    Unsupported,
+}
+
+/// SQL 方言，描述各数据库在语法上的差异，第三方数据库实现该 trait 即可接入渲染
+pub trait RdbcSqlDialect {
+    /// 引用单段标识符（不含 '.'），默认仅对保留字加双引号
+    fn quote_ident(&self, ident: &str) -> String {
+        if self.is_reserved_word(ident) {
+            quote_plain_ident(ident, '"')
+        } else {
+            ident.to_string()
+        }
+    }
+    /// 是否为需要引用的保留字
+    fn is_reserved_word(&self, ident: &str) -> bool {
+        is_sql_reserved_word(ident)
+    }
+    /// 递归公共表表达式是否需要 RECURSIVE 关键字
+    fn with_recursive_keyword(&self) -> bool {
+        true
+    }
+    /// 是否支持窗口函数
+    fn support_window_func(&self) -> bool {
+        true
+    }
+    /// 第 index 个绑定参数的占位符，index 从 1 开始
+    fn placeholder(&self, index: usize) -> String;
+    /// 转换绑定参数，用于数据库不支持的类型
+    fn bind_value(&self, value: &RdbcValue) -> RdbcValue {
+        value.clone()
+    }
+    fn boolean_literal(&self, value: bool) -> String {
+        if value {
+            "TRUE".to_string()
+        } else {
+            "FALSE".to_string()
+        }
+    }
+    /// 字符串字面量，单引号加倍转义
+    fn string_literal(&self, value: &str) -> String {
+        format!("'{}'", value.replace('\'', "''"))
+    }
+    /// 二进制字面量
+    fn bytes_literal(&self, value: &[u8]) -> String {
+        format!("X'{}'", hex_string(value))
+    }
+    /// 日期时间字面量，keyword 为 DATE、TIME、TIMESTAMP
+    fn datetime_literal(&self, keyword: &str, value: &str) -> String {
+        format!("{} {}", keyword, self.string_literal(value))
+    }
+    /// 数组字面量，items 为已渲染的元素
+    fn array_literal(&self, items: &[String]) -> String {
+        format!("({})", items.join(", "))
+    }
+    /// 将值渲染为可直接执行的字面量，无法表示的数值返回错误
+    fn literal(&self, value: &RdbcValue) -> Result<String, RdbcError> {
+        let literal = match value {
+            RdbcValue::Char(value) => self.string_literal(&value.to_string()),
+            RdbcValue::Varchar(value) | RdbcValue::Text(value) | RdbcValue::LongText(value) => {
+                self.string_literal(value)
+            }
+            RdbcValue::SmallInt(value) => value.to_string(),
+            RdbcValue::Int(value) => value.to_string(),
+            RdbcValue::BigInt(value) => value.to_string(),
+            RdbcValue::TimeStamp(value) => value.to_string(),
+            RdbcValue::Double(value) if value.is_finite() => value.to_string(),
+            RdbcValue::BigDouble(value) if value.is_finite() => value.to_string(),
+            RdbcValue::Decimal(value) if is_decimal(value) => value.clone(),
+            RdbcValue::Double(_) | RdbcValue::BigDouble(_) | RdbcValue::Decimal(_) => {
+                return Err(RdbcError::new(
+                    RdbcErrKind::SQL,
+                    format!("数值{}无法渲染为字面量", value),
+                ))
+            }
+            RdbcValue::Date(value) => {
+                self.datetime_literal("DATE", &value.format("%Y-%m-%d").to_string())
+            }
+            RdbcValue::DateTime(value) => self.datetime_literal(
+                "TIMESTAMP",
+                &value.format("%Y-%m-%d %H:%M:%S%.f").to_string(),
+            ),
+            RdbcValue::Time(value) => {
+                self.datetime_literal("TIME", &value.format("%H:%M:%S%.f").to_string())
+            }
+            RdbcValue::Bytes(value) => self.bytes_literal(value),
+            RdbcValue::Boolean(value) => self.boolean_literal(*value),
+            RdbcValue::Array(items) => {
+                let items = items
+                    .iter()
+                    .map(|item| self.literal(item))
+                    .collect::<Result<Vec<String>, RdbcError>>()?;
+                self.array_literal(&items)
+            }
+            RdbcValue::Object(_) => self.string_literal(&json_value(value).to_string()),
+            RdbcValue::Null => "NULL".to_string(),
+        };
+        Ok(literal)
+    }
+    /// 字符串拼接
+    fn concat(&self, items: &[&str]) -> String {
+        items.join(" || ")
+    }
+    /// 取模
+    fn modulo(&self, left: &str, right: &str) -> String {
+        format!("{} % {}", left, right)
+    }
+    /// 字符长度
+    fn length(&self, expr: &str) -> String {
+        format!("LENGTH({})", expr)
+    }
+    /// 多列去重计数，多数数据库不支持时返回 None
+    fn count_distinct(&self, items: &[String]) -> Option<String> {
+        match items {
+            [item] => Some(format!("COUNT(DISTINCT {})", item)),
+            _ => None,
+        }
+    }
+    /// 字符串聚合，separator 为已转义的字面量
+    fn string_agg(&self, expr: &str, separator: &str) -> String {
+        format!("STRING_AGG({}, {})", expr, separator)
+    }
+    /// 截断日期到指定单位
+    fn date_trunc(&self, unit: &RdbcDateUnit, expr: &str) -> String {
+        format!(
+            "DATE_TRUNC('{}', {})",
+            date_unit_name(unit).to_lowercase(),
+            expr
+        )
+    }
+    /// 提取日期的指定部分
+    fn extract(&self, unit: &RdbcDateUnit, expr: &str) -> String {
+        format!("EXTRACT({} FROM {})", date_unit_name(unit), expr)
+    }
+    /// 日期加减，amount 为负数时表示减
+    fn date_add(&self, expr: &str, amount: i64, unit: &RdbcDateUnit) -> String {
+        format!(
+            "({} + INTERVAL '{} {}')",
+            expr,
+            amount,
+            date_unit_name(unit).to_lowercase()
+        )
+    }
+    /// 为查询语句追加分页
+    fn render_page(&self, sql: String, limit: Option<u64>, offset: Option<u64>) -> String {
+        let mut sql = sql;
+        if let Some(limit) = limit {
+            sql.push_str(&format!(" LIMIT {}", limit));
+        }
+        if let Some(offset) = offset {
+            sql.push_str(&format!(" OFFSET {}", offset));
+        }
+        sql
+    }
+    /// 空串是否按 NULL 处理
+    fn empty_string_as_null(&self) -> bool {
+        false
+    }
+    fn support_returning(&self) -> bool {
+        false
+    }
+    /// 当前连接最后生成的自增值表达式，用于模拟 RETURNING
+    fn last_insert_id(&self) -> Option<&'static str> {
+        None
+    }
+    fn support_right_join(&self) -> bool {
+        true
+    }
+    fn support_full_join(&self) -> bool {
+        true
+    }
+    fn support_lateral_join(&self) -> bool {
+        true
+    }
+    fn support_on_conflict(&self) -> bool {
+        false
+    }
+    /// INSERT ... SELECT 后接 ON CONFLICT 时，SELECT 是否须带 WHERE 以免 ON 被解析为连接条件
+    fn conflict_select_need_where(&self) -> bool {
+        false
+    }
+    /// 插入语句的目标表是否可以使用 AS 别名
+    fn support_insert_alias(&self) -> bool {
+        false
+    }
+    fn upsert_style(&self) -> RdbcUpsertStyle {
+        if self.support_on_conflict() {
+            RdbcUpsertStyle::OnConflict
+        } else {
+            RdbcUpsertStyle::Unsupported
+        }
+    }
+    /// ON CONFLICT 是否支持 ON CONSTRAINT 指定约束名
+    fn support_conflict_constraint(&self) -> bool {
+        false
+    }
+    fn update_join_style(&self) -> RdbcUpdateJoinStyle {
+        RdbcUpdateJoinStyle::Unsupported
+    }
+    /// 单表 UPDATE 是否支持 ORDER BY、LIMIT
+    fn support_update_limit(&self) -> bool {
+        false
+    }
+    fn delete_join_style(&self) -> RdbcDeleteJoinStyle {
+        RdbcDeleteJoinStyle::Unsupported
+    }
+    /// 单表 DELETE 是否支持 ORDER BY、LIMIT
+    fn support_delete_limit(&self) -> bool {
+        false
+    }
+    /// 行锁子句，of 为已转义的表名，无法表达时返回 None
+    fn lock_clause(
+        &self,
+        mode: &RdbcLockMode,
+        of: &[String],
+        wait: &RdbcLockWait,
+    ) -> Option<String> {
+        Some(lock_clause(mode, of, wait))
+    }
+    /// 分页查询是否可以加行锁
+    fn support_lock_with_page(&self) -> bool {
+        true
+    }
+    /// 集合查询关键字，不支持时返回 None
+    fn compound_keyword(&self, compound_type: &RdbcCompoundType) -> Option<&'static str> {
+        Some(compound_keyword(compound_type))
+    }
+    /// 集合查询的成员是否可以加括号
+    fn support_compound_paren(&self) -> bool {
+        true
+    }
+    fn support_distinct_on(&self) -> bool {
+        false
+    }
+}
+
+impl<T: RdbcSqlDialect + ?Sized> RdbcSqlDialect for Box<T> {
+    fn quote_ident(&self, ident: &str) -> String {
+        (**self).quote_ident(ident)
+    }
+    fn is_reserved_word(&self, ident: &str) -> bool {
+        (**self).is_reserved_word(ident)
+    }
+    fn with_recursive_keyword(&self) -> bool {
+        (**self).with_recursive_keyword()
+    }
+    fn support_window_func(&self) -> bool {
+        (**self).support_window_func()
+    }
+    fn placeholder(&self, index: usize) -> String {
+        (**self).placeholder(index)
+    }
+    fn bind_value(&self, value: &RdbcValue) -> RdbcValue {
+        (**self).bind_value(value)
+    }
+    fn boolean_literal(&self, value: bool) -> String {
+        (**self).boolean_literal(value)
+    }
+    fn string_literal(&self, value: &str) -> String {
+        (**self).string_literal(value)
+    }
+    fn bytes_literal(&self, value: &[u8]) -> String {
+        (**self).bytes_literal(value)
+    }
+    fn datetime_literal(&self, keyword: &str, value: &str) -> String {
+        (**self).datetime_literal(keyword, value)
+    }
+    fn array_literal(&self, items: &[String]) -> String {
+        (**self).array_literal(items)
+    }
+    fn literal(&self, value: &RdbcValue) -> Result<String, RdbcError> {
+        (**self).literal(value)
+    }
+    fn concat(&self, items: &[&str]) -> String {
+        (**self).concat(items)
+    }
+    fn modulo(&self, left: &str, right: &str) -> String {
+        (**self).modulo(left, right)
+    }
+    fn length(&self, expr: &str) -> String {
+        (**self).length(expr)
+    }
+    fn count_distinct(&self, items: &[String]) -> Option<String> {
+        (**self).count_distinct(items)
+    }
+    fn string_agg(&self, expr: &str, separator: &str) -> String {
+        (**self).string_agg(expr, separator)
+    }
+    fn date_trunc(&self, unit: &RdbcDateUnit, expr: &str) -> String {
+        (**self).date_trunc(unit, expr)
+    }
+    fn extract(&self, unit: &RdbcDateUnit, expr: &str) -> String {
+        (**self).extract(unit, expr)
+    }
+    fn date_add(&self, expr: &str, amount: i64, unit: &RdbcDateUnit) -> String {
+        (**self).date_add(expr, amount, unit)
+    }
+    fn render_page(&self, sql: String, limit: Option<u64>, offset: Option<u64>) -> String {
+        (**self).render_page(sql, limit, offset)
+    }
+    fn empty_string_as_null(&self) -> bool {
+        (**self).empty_string_as_null()
+    }
+    fn support_returning(&self) -> bool {
+        (**self).support_returning()
+    }
+    fn last_insert_id(&self) -> Option<&'static str> {
+        (**self).last_insert_id()
+    }
+    fn support_right_join(&self) -> bool {
+        (**self).support_right_join()
+    }
+    fn support_full_join(&self) -> bool {
+        (**self).support_full_join()
+    }
+    fn support_lateral_join(&self) -> bool {
+        (**self).support_lateral_join()
+    }
+    fn support_on_conflict(&self) -> bool {
+        (**self).support_on_conflict()
+    }
+    fn conflict_select_need_where(&self) -> bool {
+        (**self).conflict_select_need_where()
+    }
+    fn support_insert_alias(&self) -> bool {
+        (**self).support_insert_alias()
+    }
+    fn upsert_style(&self) -> RdbcUpsertStyle {
+        (**self).upsert_style()
+    }
+    fn support_conflict_constraint(&self) -> bool {
+        (**self).support_conflict_constraint()
+    }
+    fn update_join_style(&self) -> RdbcUpdateJoinStyle {
+        (**self).update_join_style()
+    }
+    fn support_update_limit(&self) -> bool {
+        (**self).support_update_limit()
+    }
+    fn delete_join_style(&self) -> RdbcDeleteJoinStyle {
+        (**self).delete_join_style()
+    }
+    fn support_delete_limit(&self) -> bool {
+        (**self).support_delete_limit()
+    }
+    fn lock_clause(
+        &self,
+        mode: &RdbcLockMode,
+        of: &[String],
+        wait: &RdbcLockWait,
+    ) -> Option<String> {
+        (**self).lock_clause(mode, of, wait)
+    }
+    fn support_lock_with_page(&self) -> bool {
+        (**self).support_lock_with_page()
+    }
+    fn compound_keyword(&self, compound_type: &RdbcCompoundType) -> Option<&'static str> {
+        (**self).compound_keyword(compound_type)
+    }
+    fn support_compound_paren(&self) -> bool {
+        (**self).support_compound_paren()
+    }
+    fn support_distinct_on(&self) -> bool {
+        (**self).support_distinct_on()
+    }
+}
+
+/// 标准行锁子句 FOR mode [OF tables] [NOWAIT | SKIP LOCKED]
+pub fn lock_clause(mode: &RdbcLockMode, of: &[String], wait: &RdbcLockWait) -> String {
+    let mut sql = match mode {
+        RdbcLockMode::Update => "FOR UPDATE",
+        RdbcLockMode::NoKeyUpdate => "FOR NO KEY UPDATE",
+        RdbcLockMode::Share => "FOR SHARE",
+        RdbcLockMode::KeyShare => "FOR KEY SHARE",
+    }
+    .to_string();
+    if !of.is_empty() {
+        sql.push_str(&format!(" OF {}", of.join(", ")));
+    }
+    match wait {
+        RdbcLockWait::Wait => {}
+        RdbcLockWait::NoWait => sql.push_str(" NOWAIT"),
+        RdbcLockWait::SkipLocked => sql.push_str(" SKIP LOCKED"),
+    }
+    sql
+}
+
+/// 标准集合查询关键字
+pub fn compound_keyword(compound_type: &RdbcCompoundType) -> &'static str {
+    match compound_type {
+        RdbcCompoundType::Union => "UNION",
+        RdbcCompoundType::UnionAll => "UNION ALL",
+        RdbcCompoundType::Intersect => "INTERSECT",
+        RdbcCompoundType::IntersectAll => "INTERSECT ALL",
+        RdbcCompoundType::Except => "EXCEPT",
+        RdbcCompoundType::ExceptAll => "EXCEPT ALL",
+    }
+}
+
+/// 日期单位的 SQL 关键字
+pub fn date_unit_name(unit: &RdbcDateUnit) -> &'static str {
+    match unit {
+        RdbcDateUnit::Year => "YEAR",
+        RdbcDateUnit::Month => "MONTH",
+        RdbcDateUnit::Day => "DAY",
+        RdbcDateUnit::Hour => "HOUR",
+        RdbcDateUnit::Minute => "MINUTE",
+        RdbcDateUnit::Second => "SECOND",
+    }
+}
+
+/// 十六进制编码，用于二进制字面量
+pub fn hex_string(value: &[u8]) -> String {
+    value
+        .iter()
+        .map(|byte| format!("{:02X}", byte))
+        .collect::<String>()
+}
+
+/// 是否为合法的数字文本，避免 Decimal 值直接拼入 SQL
+fn is_decimal(value: &str) -> bool {
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    let mut parts = digits.splitn(2, '.');
+    let integer = parts.next().unwrap_or("");
+    let fraction = parts.next().unwrap_or("0");
+    !integer.is_empty()
+        && !fraction.is_empty()
+        && integer.chars().all(|c| c.is_ascii_digit())
+        && fraction.chars().all(|c| c.is_ascii_digit())
+}
+
+/// 转换为 JSON，用于对象值的字面量
+fn json_value(value: &RdbcValue) -> serde_json::Value {
+    match value {
+        RdbcValue::Null => serde_json::Value::Null,
+        RdbcValue::Boolean(value) => serde_json::Value::Bool(*value),
+        RdbcValue::SmallInt(value) => serde_json::Value::from(*value),
+        RdbcValue::Int(value) => serde_json::Value::from(*value),
+        RdbcValue::BigInt(value) => serde_json::Value::from(*value),
+        RdbcValue::TimeStamp(value) => serde_json::Value::from(*value),
+        RdbcValue::Double(value) => serde_json::Value::from(*value),
+        RdbcValue::BigDouble(value) => serde_json::Value::from(*value),
+        RdbcValue::Decimal(value) => value
+            .parse::<serde_json::Number>()
+            .map(serde_json::Value::Number)
+            .unwrap_or_else(|_| serde_json::Value::String(value.clone())),
+        RdbcValue::Array(items) => serde_json::Value::Array(items.iter().map(json_value).collect()),
+        RdbcValue::Object(object) => serde_json::Value::Object(
+            object
+                .iter()
+                .map(|(key, value)| (key.clone(), json_value(value)))
+                .collect(),
+        ),
+        _ => serde_json::Value::String(value.to_string()),
+    }
+}
+
+/// 常见数据库共同的保留字
+const SQL_RESERVED_WORDS: &[&str] = &[
+    "ALL",
+    "ALTER",
+    "AND",
+    "ANY",
+    "AS",
+    "ASC",
+    "BETWEEN",
+    "BY",
+    "CASE",
+    "CAST",
+    "CHECK",
+    "COLUMN",
+    "CONSTRAINT",
+    "CREATE",
+    "CROSS",
+    "CURRENT_DATE",
+    "CURRENT_TIME",
+    "CURRENT_TIMESTAMP",
+    "CURRENT_USER",
+    "DEFAULT",
+    "DELETE",
+    "DESC",
+    "DISTINCT",
+    "DROP",
+    "ELSE",
+    "END",
+    "EXCEPT",
+    "EXISTS",
+    "FALSE",
+    "FETCH",
+    "FOR",
+    "FOREIGN",
+    "FROM",
+    "FULL",
+    "GRANT",
+    "GROUP",
+    "HAVING",
+    "IN",
+    "INNER",
+    "INSERT",
+    "INTERSECT",
+    "INTO",
+    "IS",
+    "JOIN",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "NATURAL",
+    "NOT",
+    "NULL",
+    "OFFSET",
+    "ON",
+    "OR",
+    "ORDER",
+    "OUTER",
+    "PRIMARY",
+    "REFERENCES",
+    "RIGHT",
+    "SELECT",
+    "SET",
+    "SOME",
+    "TABLE",
+    "THEN",
+    "TO",
+    "TRUE",
+    "UNION",
+    "UNIQUE",
+    "UPDATE",
+    "USER",
+    "USING",
+    "VALUES",
+    "WHEN",
+    "WHERE",
+    "WINDOW",
+    "WITH",
+];
+
+/// 是否为常见保留字，不区分大小写
+pub fn is_sql_reserved_word(ident: &str) -> bool {
+    SQL_RESERVED_WORDS
+        .iter()
+        .any(|word| word.eq_ignore_ascii_case(ident))
+}
+
+/// 使用指定引号引用单段标识符，标识符中的引号加倍转义
+pub fn quote_plain_ident(ident: &str, quote: char) -> String {
+    if ident == "*" {
+        return ident.to_string();
+    }
+    let escaped = ident.replace(quote, &format!("{}{}", quote, quote));
+    format!("{}{}{}", quote, escaped, quote)
+}
+
+/// 拆分 schema.table.column 形式的标识符，包含非法字符时返回错误
+pub fn split_ident(ident: &str) -> Result<Vec<&str>, RdbcError> {
+    let parts = ident.split('.').collect::<Vec<&str>>();
+    for (index, part) in parts.iter().enumerate() {
+        if !is_plain_ident(part, index == parts.len() - 1) {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                format!("非法标识符{}，表达式请使用 raw 系列方法", ident),
+            ));
+        }
+    }
+    Ok(parts)
+}
+
+fn is_plain_ident(part: &str, last: bool) -> bool {
+    if part == "*" {
+        return last;
+    }
+    let mut chars = part.chars();
+    match chars.next() {
+        Some(first) if first.is_alphabetic() || first == '_' => {
+            chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+        }
+        _ => false,
+    }
+}
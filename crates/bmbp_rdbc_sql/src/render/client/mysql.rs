@@ -0,0 +1,131 @@
+use crate::{
+    date_unit_name, lock_clause, quote_plain_ident, RdbcDateUnit, RdbcDeleteJoinStyle,
+    RdbcLockMode, RdbcLockWait, RdbcSqlDialect, RdbcUpdateJoinStyle, RdbcUpsertStyle,
+};
+
+/// MySQL 方言，携带目标版本号，如 8.0.14 记为 8_000_014
+#[derive(Debug, Clone, Copy)]
+pub struct RdbcMysqlDialect {
+    version: u32,
+}
+
+impl RdbcMysqlDialect {
+    /// 自 8.0.14 起支持 LATERAL 派生表
+    pub const LATERAL_VERSION: u32 = 8_000_014;
+
+    pub fn new(version: u32) -> Self {
+        RdbcMysqlDialect { version }
+    }
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+}
+
+impl Default for RdbcMysqlDialect {
+    fn default() -> Self {
+        Self::new(Self::LATERAL_VERSION)
+    }
+}
+
+impl RdbcSqlDialect for RdbcMysqlDialect {
+    fn quote_ident(&self, ident: &str) -> String {
+        quote_plain_ident(ident, '`')
+    }
+    fn placeholder(&self, _index: usize) -> String {
+        "?".to_string()
+    }
+    /// MySQL 默认将反斜杠作为转义符
+    fn string_literal(&self, value: &str) -> String {
+        let mut literal = String::with_capacity(value.len() + 2);
+        literal.push('\'');
+        for c in value.chars() {
+            match c {
+                '\'' => literal.push_str("''"),
+                '\\' => literal.push_str("\\\\"),
+                '\0' => literal.push_str("\\0"),
+                _ => literal.push(c),
+            }
+        }
+        literal.push('\'');
+        literal
+    }
+    fn concat(&self, items: &[&str]) -> String {
+        format!("CONCAT({})", items.join(", "))
+    }
+    /// LENGTH 返回字节数，字符长度使用 CHAR_LENGTH
+    fn length(&self, expr: &str) -> String {
+        format!("CHAR_LENGTH({})", expr)
+    }
+    fn count_distinct(&self, items: &[String]) -> Option<String> {
+        Some(format!("COUNT(DISTINCT {})", items.join(", ")))
+    }
+    fn string_agg(&self, expr: &str, separator: &str) -> String {
+        format!("GROUP_CONCAT({} SEPARATOR {})", expr, separator)
+    }
+    /// MySQL 没有 DATE_TRUNC，按格式化还原为日期时间
+    fn date_trunc(&self, unit: &RdbcDateUnit, expr: &str) -> String {
+        let format = match unit {
+            RdbcDateUnit::Year => "%Y-01-01",
+            RdbcDateUnit::Month => "%Y-%m-01",
+            RdbcDateUnit::Day => "%Y-%m-%d",
+            RdbcDateUnit::Hour => "%Y-%m-%d %H:00:00",
+            RdbcDateUnit::Minute => "%Y-%m-%d %H:%i:00",
+            RdbcDateUnit::Second => "%Y-%m-%d %H:%i:%s",
+        };
+        format!("CAST(DATE_FORMAT({}, '{}') AS DATETIME)", expr, format)
+    }
+    fn date_add(&self, expr: &str, amount: i64, unit: &RdbcDateUnit) -> String {
+        format!(
+            "DATE_ADD({}, INTERVAL {} {})",
+            expr,
+            amount,
+            date_unit_name(unit)
+        )
+    }
+    fn render_page(&self, sql: String, limit: Option<u64>, offset: Option<u64>) -> String {
+        match (limit, offset) {
+            (Some(limit), Some(offset)) => format!("{} LIMIT {}, {}", sql, offset, limit),
+            (Some(limit), None) => format!("{} LIMIT {}", sql, limit),
+            // MySQL 不支持单独的 OFFSET，使用最大行数代替
+            (None, Some(offset)) => format!("{} LIMIT {}, {}", sql, offset, u64::MAX),
+            (None, None) => sql,
+        }
+    }
+    fn support_full_join(&self) -> bool {
+        false
+    }
+    fn support_lateral_join(&self) -> bool {
+        self.version >= Self::LATERAL_VERSION
+    }
+    fn last_insert_id(&self) -> Option<&'static str> {
+        Some("LAST_INSERT_ID()")
+    }
+    /// 冲突目标由表上任一唯一键决定，不能显式指定
+    fn upsert_style(&self) -> RdbcUpsertStyle {
+        RdbcUpsertStyle::OnDuplicateKey
+    }
+    fn update_join_style(&self) -> RdbcUpdateJoinStyle {
+        RdbcUpdateJoinStyle::Join
+    }
+    fn support_update_limit(&self) -> bool {
+        true
+    }
+    fn delete_join_style(&self) -> RdbcDeleteJoinStyle {
+        RdbcDeleteJoinStyle::Join
+    }
+    fn support_delete_limit(&self) -> bool {
+        true
+    }
+    /// MySQL 8.0 仅支持 FOR UPDATE、FOR SHARE
+    fn lock_clause(
+        &self,
+        mode: &RdbcLockMode,
+        of: &[String],
+        wait: &RdbcLockWait,
+    ) -> Option<String> {
+        match mode {
+            RdbcLockMode::Update | RdbcLockMode::Share => Some(lock_clause(mode, of, wait)),
+            RdbcLockMode::NoKeyUpdate | RdbcLockMode::KeyShare => None,
+        }
+    }
+}
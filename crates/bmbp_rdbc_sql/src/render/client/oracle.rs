@@ -0,0 +1,163 @@
+use crate::{
+    compound_keyword, date_unit_name, hex_string, lock_clause, quote_plain_ident, RdbcCompoundType,
+    RdbcDateUnit, RdbcLockMode, RdbcLockWait, RdbcSqlDialect, RdbcUpsertStyle,
+};
+
+/// Oracle 方言，携带主版本号，如 11、12
+#[derive(Debug, Clone, Copy)]
+pub struct RdbcOracleDialect {
+    version: u32,
+}
+
+impl RdbcOracleDialect {
+    /// 自 12c 起支持 OFFSET ... FETCH 分页
+    pub const FETCH_VERSION: u32 = 12;
+    /// 自 12c 起支持 LATERAL 内联视图
+    pub const LATERAL_VERSION: u32 = 12;
+    /// 自 21c 起支持 EXCEPT、INTERSECT ALL、EXCEPT ALL
+    pub const COMPOUND_ALL_VERSION: u32 = 21;
+
+    pub fn new(version: u32) -> Self {
+        RdbcOracleDialect { version }
+    }
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+}
+
+impl Default for RdbcOracleDialect {
+    fn default() -> Self {
+        Self::new(Self::FETCH_VERSION)
+    }
+}
+
+impl RdbcSqlDialect for RdbcOracleDialect {
+    fn quote_ident(&self, ident: &str) -> String {
+        quote_plain_ident(ident, '"')
+    }
+    /// Oracle 递归子查询不使用 RECURSIVE 关键字，但必须声明列名
+    fn with_recursive_keyword(&self) -> bool {
+        false
+    }
+    fn placeholder(&self, index: usize) -> String {
+        format!(":{}", index)
+    }
+    fn boolean_literal(&self, value: bool) -> String {
+        if value {
+            "1".to_string()
+        } else {
+            "0".to_string()
+        }
+    }
+    fn bytes_literal(&self, value: &[u8]) -> String {
+        format!("HEXTORAW('{}')", hex_string(value))
+    }
+    /// Oracle 没有 TIME 类型，使用字符串
+    fn datetime_literal(&self, keyword: &str, value: &str) -> String {
+        if keyword == "TIME" {
+            return self.string_literal(value);
+        }
+        format!("{} {}", keyword, self.string_literal(value))
+    }
+    /// expr 可能带有绑定参数，不能在 ORDER BY 中重复出现
+    fn string_agg(&self, expr: &str, separator: &str) -> String {
+        format!(
+            "LISTAGG({}, {}) WITHIN GROUP (ORDER BY NULL)",
+            expr, separator
+        )
+    }
+    /// Oracle 不支持 % 运算符
+    fn modulo(&self, left: &str, right: &str) -> String {
+        format!("MOD({}, {})", left, right)
+    }
+    fn date_trunc(&self, unit: &RdbcDateUnit, expr: &str) -> String {
+        let format = match unit {
+            RdbcDateUnit::Year => "YYYY",
+            RdbcDateUnit::Month => "MM",
+            RdbcDateUnit::Day => "DD",
+            RdbcDateUnit::Hour => "HH24",
+            RdbcDateUnit::Minute => "MI",
+            // TRUNC 最小精度为分钟，转换为 DATE 去掉秒以下部分
+            RdbcDateUnit::Second => return format!("CAST({} AS DATE)", expr),
+        };
+        format!("TRUNC({}, '{}')", expr, format)
+    }
+    /// 年月使用 ADD_MONTHS，避免月末日期溢出报错
+    fn date_add(&self, expr: &str, amount: i64, unit: &RdbcDateUnit) -> String {
+        match unit {
+            RdbcDateUnit::Year => format!("ADD_MONTHS({}, {})", expr, amount * 12),
+            RdbcDateUnit::Month => format!("ADD_MONTHS({}, {})", expr, amount),
+            _ => format!(
+                "({} + NUMTODSINTERVAL({}, '{}'))",
+                expr,
+                amount,
+                date_unit_name(unit)
+            ),
+        }
+    }
+    fn render_page(&self, sql: String, limit: Option<u64>, offset: Option<u64>) -> String {
+        if self.version >= Self::FETCH_VERSION {
+            return match (limit, offset) {
+                (Some(limit), Some(offset)) => format!(
+                    "{} OFFSET {} ROWS FETCH NEXT {} ROWS ONLY",
+                    sql, offset, limit
+                ),
+                (Some(limit), None) => format!("{} FETCH FIRST {} ROWS ONLY", sql, limit),
+                (None, Some(offset)) => format!("{} OFFSET {} ROWS", sql, offset),
+                (None, None) => sql,
+            };
+        }
+        // 12c 之前使用 ROWNUM 包装
+        match (limit, offset) {
+            (Some(limit), Some(offset)) => format!(
+                "SELECT * FROM (SELECT t_.*, ROWNUM rn_ FROM ({}) t_ WHERE ROWNUM <= {}) WHERE rn_ > {}",
+                sql,
+                offset.saturating_add(limit),
+                offset
+            ),
+            (Some(limit), None) => format!("SELECT * FROM ({}) WHERE ROWNUM <= {}", sql, limit),
+            (None, Some(offset)) => format!(
+                "SELECT * FROM (SELECT t_.*, ROWNUM rn_ FROM ({}) t_) WHERE rn_ > {}",
+                sql, offset
+            ),
+            (None, None) => sql,
+        }
+    }
+    /// Oracle 将空串视为 NULL
+    fn empty_string_as_null(&self) -> bool {
+        true
+    }
+    /// 21c 之前 EXCEPT 使用 MINUS，且不支持 ALL
+    fn compound_keyword(&self, compound_type: &RdbcCompoundType) -> Option<&'static str> {
+        if self.version >= Self::COMPOUND_ALL_VERSION {
+            return Some(compound_keyword(compound_type));
+        }
+        match compound_type {
+            RdbcCompoundType::Except => Some("MINUS"),
+            RdbcCompoundType::IntersectAll | RdbcCompoundType::ExceptAll => None,
+            _ => Some(compound_keyword(compound_type)),
+        }
+    }
+    fn support_lateral_join(&self) -> bool {
+        self.version >= Self::LATERAL_VERSION
+    }
+    fn upsert_style(&self) -> RdbcUpsertStyle {
+        RdbcUpsertStyle::Merge
+    }
+    /// 仅支持 FOR UPDATE，Oracle 的 OF 指定的是列而非表
+    fn lock_clause(
+        &self,
+        mode: &RdbcLockMode,
+        of: &[String],
+        wait: &RdbcLockWait,
+    ) -> Option<String> {
+        match mode {
+            RdbcLockMode::Update if of.is_empty() => Some(lock_clause(mode, of, wait)),
+            _ => None,
+        }
+    }
+    /// FETCH FIRST 与 ROWNUM 子查询均不能与 FOR UPDATE 同用
+    fn support_lock_with_page(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{hex_string, RdbcDeleteJoinStyle, RdbcSqlDialect, RdbcUpdateJoinStyle};
+
+/// PostgreSQL 方言
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RdbcPostgresDialect;
+
+impl RdbcSqlDialect for RdbcPostgresDialect {
+    fn placeholder(&self, index: usize) -> String {
+        format!("${}", index)
+    }
+    fn bytes_literal(&self, value: &[u8]) -> String {
+        format!("'\\x{}'::bytea", hex_string(value))
+    }
+    fn array_literal(&self, items: &[String]) -> String {
+        if items.is_empty() {
+            return "'{}'".to_string();
+        }
+        format!("ARRAY[{}]", items.join(", "))
+    }
+    /// 多列时按行值去重
+    fn count_distinct(&self, items: &[String]) -> Option<String> {
+        match items {
+            [item] => Some(format!("COUNT(DISTINCT {})", item)),
+            _ => Some(format!("COUNT(DISTINCT ({}))", items.join(", "))),
+        }
+    }
+    fn support_returning(&self) -> bool {
+        true
+    }
+    fn support_on_conflict(&self) -> bool {
+        true
+    }
+    fn support_conflict_constraint(&self) -> bool {
+        true
+    }
+    fn support_insert_alias(&self) -> bool {
+        true
+    }
+    fn support_distinct_on(&self) -> bool {
+        true
+    }
+    fn update_join_style(&self) -> RdbcUpdateJoinStyle {
+        RdbcUpdateJoinStyle::From
+    }
+    fn delete_join_style(&self) -> RdbcDeleteJoinStyle {
+        RdbcDeleteJoinStyle::Using
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{
+    compound_keyword, date_unit_name, RdbcCompoundType, RdbcDateUnit, RdbcLockMode, RdbcLockWait,
+    RdbcSqlDialect, RdbcUpdateJoinStyle,
+};
+use bmbp_rdbc_type::RdbcValue;
+
+/// SQLite 方言，携带目标版本号，如 3.39.0 记为 3_039_000
+#[derive(Debug, Clone, Copy)]
+pub struct RdbcSqliteDialect {
+    version: u32,
+}
+
+impl RdbcSqliteDialect {
+    /// 自 3.24.0 起支持 ON CONFLICT
+    pub const ON_CONFLICT_VERSION: u32 = 3_024_000;
+    /// 自 3.25.0 起支持窗口函数
+    pub const WINDOW_VERSION: u32 = 3_025_000;
+    /// 自 3.33.0 起支持 UPDATE ... FROM
+    pub const UPDATE_FROM_VERSION: u32 = 3_033_000;
+    /// 自 3.35.0 起支持 RETURNING
+    pub const RETURNING_VERSION: u32 = 3_035_000;
+    /// 自 3.39.0 起支持 RIGHT JOIN、FULL JOIN
+    pub const OUTER_JOIN_VERSION: u32 = 3_039_000;
+
+    pub fn new(version: u32) -> Self {
+        RdbcSqliteDialect { version }
+    }
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+}
+
+impl Default for RdbcSqliteDialect {
+    fn default() -> Self {
+        Self::new(Self::OUTER_JOIN_VERSION)
+    }
+}
+
+impl RdbcSqlDialect for RdbcSqliteDialect {
+    fn placeholder(&self, index: usize) -> String {
+        format!("?{}", index)
+    }
+    /// SQLite 没有布尔类型，以 0/1 绑定
+    fn bind_value(&self, value: &RdbcValue) -> RdbcValue {
+        match value {
+            RdbcValue::Boolean(value) => RdbcValue::Int(if *value { 1 } else { 0 }),
+            _ => value.clone(),
+        }
+    }
+    fn boolean_literal(&self, value: bool) -> String {
+        if value {
+            "1".to_string()
+        } else {
+            "0".to_string()
+        }
+    }
+    /// SQLite 没有日期类型关键字，使用字符串
+    fn datetime_literal(&self, _keyword: &str, value: &str) -> String {
+        self.string_literal(value)
+    }
+    fn support_window_func(&self) -> bool {
+        self.version >= Self::WINDOW_VERSION
+    }
+    fn string_agg(&self, expr: &str, separator: &str) -> String {
+        format!("GROUP_CONCAT({}, {})", expr, separator)
+    }
+    /// SQLite 没有 DATE_TRUNC，使用 STRFTIME 格式化
+    fn date_trunc(&self, unit: &RdbcDateUnit, expr: &str) -> String {
+        let format = match unit {
+            RdbcDateUnit::Year => "%Y-01-01 00:00:00",
+            RdbcDateUnit::Month => "%Y-%m-01 00:00:00",
+            RdbcDateUnit::Day => "%Y-%m-%d 00:00:00",
+            RdbcDateUnit::Hour => "%Y-%m-%d %H:00:00",
+            RdbcDateUnit::Minute => "%Y-%m-%d %H:%M:00",
+            RdbcDateUnit::Second => "%Y-%m-%d %H:%M:%S",
+        };
+        format!("STRFTIME('{}', {})", format, expr)
+    }
+    fn extract(&self, unit: &RdbcDateUnit, expr: &str) -> String {
+        let format = match unit {
+            RdbcDateUnit::Year => "%Y",
+            RdbcDateUnit::Month => "%m",
+            RdbcDateUnit::Day => "%d",
+            RdbcDateUnit::Hour => "%H",
+            RdbcDateUnit::Minute => "%M",
+            RdbcDateUnit::Second => "%S",
+        };
+        format!("CAST(STRFTIME('{}', {}) AS INTEGER)", format, expr)
+    }
+    fn date_add(&self, expr: &str, amount: i64, unit: &RdbcDateUnit) -> String {
+        format!(
+            "DATETIME({}, '{:+} {}s')",
+            expr,
+            amount,
+            date_unit_name(unit).to_lowercase()
+        )
+    }
+    fn render_page(&self, sql: String, limit: Option<u64>, offset: Option<u64>) -> String {
+        match (limit, offset) {
+            (Some(limit), Some(offset)) => format!("{} LIMIT {} OFFSET {}", sql, limit, offset),
+            (Some(limit), None) => format!("{} LIMIT {}", sql, limit),
+            // SQLite 的 OFFSET 必须跟在 LIMIT 之后，-1 表示不限行数
+            (None, Some(offset)) => format!("{} LIMIT -1 OFFSET {}", sql, offset),
+            (None, None) => sql,
+        }
+    }
+    fn support_returning(&self) -> bool {
+        self.version >= Self::RETURNING_VERSION
+    }
+    fn last_insert_id(&self) -> Option<&'static str> {
+        Some("last_insert_rowid()")
+    }
+    fn support_right_join(&self) -> bool {
+        self.version >= Self::OUTER_JOIN_VERSION
+    }
+    fn support_full_join(&self) -> bool {
+        self.version >= Self::OUTER_JOIN_VERSION
+    }
+    fn support_lateral_join(&self) -> bool {
+        false
+    }
+    fn support_on_conflict(&self) -> bool {
+        self.version >= Self::ON_CONFLICT_VERSION
+    }
+    fn conflict_select_need_where(&self) -> bool {
+        true
+    }
+    fn update_join_style(&self) -> RdbcUpdateJoinStyle {
+        if self.version >= Self::UPDATE_FROM_VERSION {
+            RdbcUpdateJoinStyle::From
+        } else {
+            RdbcUpdateJoinStyle::Unsupported
+        }
+    }
+    /// 不支持 INTERSECT ALL、EXCEPT ALL
+    fn compound_keyword(&self, compound_type: &RdbcCompoundType) -> Option<&'static str> {
+        match compound_type {
+            RdbcCompoundType::IntersectAll | RdbcCompoundType::ExceptAll => None,
+            _ => Some(compound_keyword(compound_type)),
+        }
+    }
+    /// SQLite 不允许括号包裹集合查询的成员
+    fn support_compound_paren(&self) -> bool {
+        false
+    }
+    /// SQLite 以数据库文件为锁粒度，没有行锁子句
+    fn lock_clause(
+        &self,
+        _mode: &RdbcLockMode,
+        _of: &[String],
+        _wait: &RdbcLockWait,
+    ) -> Option<String> {
+        None
+    }
+}
@@ -0,0 +1,218 @@
+use crate::render::dmq::render::RdbcRenderContext;
+use crate::{
+    RdbcBinaryExpr, RdbcCaseColumn, RdbcColumn, RdbcExprColumn, RdbcGroupColumn, RdbcOperator,
+    RdbcOrderColumn, RdbcOrderType, RdbcSelectColumn, RdbcTableColumn,
+};
+use bmbp_rdbc_type::{RdbcErrKind, RdbcError};
+
+impl RdbcRenderContext<'_> {
+    pub(crate) fn render_column(&mut self, column: &RdbcColumn) -> Result<String, RdbcError> {
+        let column_sql = match column {
+            RdbcColumn::SimpleColumn(column) => self.quote_ident(&column.column)?,
+            RdbcColumn::TableColumn(column) => self.render_table_column(column)?,
+            RdbcColumn::QueryColumn(column) => format!("({})", self.render_query(&column.column)?),
+            RdbcColumn::ValueColumn(column) => self.push_value(&column.column)?,
+            RdbcColumn::FuncColumn(column) => self.render_func(&column.func)?,
+            RdbcColumn::RawColumn(column) => column.column.clone(),
+            RdbcColumn::LiteralColumn(column) => self.dialect().literal(&column.column)?,
+            RdbcColumn::CaseColumn(column) => self.render_case_column(column)?,
+            RdbcColumn::ExprColumn(column) => self.render_expr_column(column)?,
+        };
+        Ok(column_sql)
+    }
+
+    fn render_case_column(&mut self, column: &RdbcCaseColumn) -> Result<String, RdbcError> {
+        if column.when.is_empty() {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "CASE 表达式缺少 WHEN 分支".to_string(),
+            ));
+        }
+        let mut sql = "CASE".to_string();
+        if let Some(operand) = column.operand.as_ref() {
+            sql.push_str(&format!(" {}", self.render_column(operand)?));
+        }
+        for when in column.when.iter() {
+            let when_sql = match (column.operand.as_ref(), &when.value, &when.filter) {
+                (Some(_), Some(value), None) => self.render_column(value)?,
+                (None, None, Some(filter)) => self.render_filter(filter)?,
+                _ => "".to_string(),
+            };
+            if when_sql.is_empty() {
+                return Err(RdbcError::new(
+                    RdbcErrKind::SQL,
+                    "CASE 表达式的 WHEN 分支缺少条件".to_string(),
+                ));
+            }
+            sql.push_str(&format!(
+                " WHEN {} THEN {}",
+                when_sql,
+                self.render_column(&when.then)?
+            ));
+        }
+        if let Some(else_) = column.else_.as_ref() {
+            sql.push_str(&format!(" ELSE {}", self.render_column(else_)?));
+        }
+        sql.push_str(" END");
+        Ok(sql)
+    }
+
+    fn render_expr_column(&mut self, column: &RdbcExprColumn) -> Result<String, RdbcError> {
+        match column {
+            RdbcExprColumn::Binary(expr) => {
+                if let RdbcOperator::Concat = expr.operator {
+                    let mut items = vec![];
+                    collect_concat_items(column, &mut items);
+                    let mut items_sql = vec![];
+                    for item in items {
+                        items_sql.push(self.render_expr_operand(item, None, false)?);
+                    }
+                    let items_ref: Vec<&str> = items_sql.iter().map(|s| s.as_str()).collect();
+                    return Ok(self.dialect().concat(&items_ref));
+                }
+                let left = self.render_expr_operand(&expr.left, Some(&expr.operator), true)?;
+                let right = self.render_expr_operand(&expr.right, Some(&expr.operator), false)?;
+                let sql = match expr.operator {
+                    RdbcOperator::Add => format!("{} + {}", left, right),
+                    RdbcOperator::Sub => format!("{} - {}", left, right),
+                    RdbcOperator::Mul => format!("{} * {}", left, right),
+                    RdbcOperator::Div => format!("{} / {}", left, right),
+                    RdbcOperator::Mod => self.dialect().modulo(&left, &right),
+                    RdbcOperator::Concat => unreachable!(),
+                };
+                Ok(sql)
+            }
+            RdbcExprColumn::Negative(column) => {
+                let column_sql = self.render_column(column)?;
+                let wrap = column_sql.starts_with('-')
+                    || matches!(
+                        column.as_ref(),
+                        RdbcColumn::ExprColumn(
+                            RdbcExprColumn::Binary(_) | RdbcExprColumn::Negative(_)
+                        )
+                    );
+                if wrap {
+                    Ok(format!("-({})", column_sql))
+                } else {
+                    Ok(format!("-{}", column_sql))
+                }
+            }
+            RdbcExprColumn::Cast(expr) => {
+                let valid = !expr.type_.trim().is_empty()
+                    && expr.type_.chars().all(|c| {
+                        c.is_ascii_alphanumeric() || matches!(c, '_' | ' ' | '(' | ')' | ',')
+                    });
+                if !valid {
+                    return Err(RdbcError::new(
+                        RdbcErrKind::SQL,
+                        format!("非法的 CAST 类型{}", expr.type_),
+                    ));
+                }
+                Ok(format!(
+                    "CAST({} AS {})",
+                    self.render_column(&expr.column)?,
+                    expr.type_.trim()
+                ))
+            }
+        }
+    }
+
+    /// 运算数为低优先级的运算表达式时加括号，同级运算仅左侧省略括号
+    fn render_expr_operand(
+        &mut self,
+        column: &RdbcColumn,
+        parent: Option<&RdbcOperator>,
+        left: bool,
+    ) -> Result<String, RdbcError> {
+        let column_sql = self.render_column(column)?;
+        let wrap = match (column, parent) {
+            (RdbcColumn::ExprColumn(RdbcExprColumn::Binary(child)), Some(parent)) => {
+                let child_level = operator_level(&child.operator);
+                let parent_level = operator_level(parent);
+                child_level == 0
+                    || child_level < parent_level
+                    || (child_level == parent_level && !left)
+            }
+            (RdbcColumn::ExprColumn(RdbcExprColumn::Binary(_)), None) => true,
+            _ => false,
+        };
+        if wrap {
+            Ok(format!("({})", column_sql))
+        } else {
+            Ok(column_sql)
+        }
+    }
+
+    pub(crate) fn render_table_column(
+        &mut self,
+        column: &RdbcTableColumn,
+    ) -> Result<String, RdbcError> {
+        let qualifier = self.render_table_qualifier(&column.table)?;
+        let column_sql = self.quote_ident(&column.column)?;
+        if qualifier.is_empty() {
+            Ok(column_sql)
+        } else {
+            Ok(format!("{}.{}", qualifier, column_sql))
+        }
+    }
+
+    pub(crate) fn render_select_column(
+        &mut self,
+        column: &RdbcSelectColumn,
+    ) -> Result<String, RdbcError> {
+        let column_sql = self.render_column(&column.column)?;
+        if column.alias.is_empty() {
+            Ok(column_sql)
+        } else {
+            Ok(format!(
+                "{} AS {}",
+                column_sql,
+                self.quote_ident(&column.alias)?
+            ))
+        }
+    }
+
+    pub(crate) fn render_order_column(
+        &mut self,
+        column: &RdbcOrderColumn,
+    ) -> Result<String, RdbcError> {
+        let column_sql = self.render_column(&column.column)?;
+        match column.order_type {
+            RdbcOrderType::Asc => Ok(format!("{} ASC", column_sql)),
+            RdbcOrderType::Desc => Ok(format!("{} DESC", column_sql)),
+        }
+    }
+
+    pub(crate) fn render_group_column(
+        &mut self,
+        column: &RdbcGroupColumn,
+    ) -> Result<String, RdbcError> {
+        self.render_column(&column.column)
+    }
+}
+
+/// 运算优先级，拼接统一加括号
+fn operator_level(operator: &RdbcOperator) -> u8 {
+    match operator {
+        RdbcOperator::Concat => 0,
+        RdbcOperator::Add | RdbcOperator::Sub => 1,
+        RdbcOperator::Mul | RdbcOperator::Div | RdbcOperator::Mod => 2,
+    }
+}
+
+/// 展开连续的拼接，避免嵌套输出
+fn collect_concat_items<'a>(column: &'a RdbcExprColumn, items: &mut Vec<&'a RdbcColumn>) {
+    if let RdbcExprColumn::Binary(expr) = column {
+        for side in [expr.left.as_ref(), expr.right.as_ref()] {
+            match side {
+                RdbcColumn::ExprColumn(
+                    child @ RdbcExprColumn::Binary(RdbcBinaryExpr {
+                        operator: RdbcOperator::Concat,
+                        ..
+                    }),
+                ) => collect_concat_items(child, items),
+                _ => items.push(side),
+            }
+        }
+    }
+}
@@ -1,20 +1,226 @@
+use crate::render::dmq::render::RdbcRenderContext;
+use crate::{
+    JoinType, RdbcDelete, RdbcDeleteJoinStyle, RdbcLockMode, RdbcLockWait, RdbcPostgresDialect,
+    RdbcReturningSql, RdbcSqlDialect, RdbcSqlRender,
+};
+use bmbp_rdbc_type::{RdbcErrKind, RdbcError, RdbcValue};
 use std::collections::HashMap;
-use bmbp_rdbc_type::RdbcValue;
-use crate::{RdbcDelete, RdbcSqlRender};
 
 pub struct RdbcDeleteRender {
-    delete: RdbcDelete
+    delete: RdbcDelete,
+    dialect: Box<dyn RdbcSqlDialect>,
 }
+
+impl RdbcDeleteRender {
+    pub fn new(delete: RdbcDelete) -> Self {
+        Self::new_with_dialect(delete, RdbcPostgresDialect)
+    }
+    pub fn new_with_dialect<D>(delete: RdbcDelete, dialect: D) -> Self
+    where
+        D: RdbcSqlDialect + 'static,
+    {
+        RdbcDeleteRender {
+            delete,
+            dialect: Box::new(dialect),
+        }
+    }
+}
+
+impl RdbcDeleteRender {
+    /// 渲染带返回行的删除，数据库不支持 RETURNING 时先查询并锁定待删除的行，需在事务中执行
+    pub fn render_returning_sql(&self) -> Result<RdbcReturningSql, RdbcError> {
+        if self.delete.returning.is_empty() {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "删除语句未指定返回列".to_string(),
+            ));
+        }
+        if self.dialect.support_returning() {
+            let (sql, values) = self.render_sql()?;
+            return Ok(RdbcReturningSql::Returning(sql, values));
+        }
+        let mut delete = self.delete.clone();
+        delete.returning.clear();
+        let mut context = RdbcRenderContext::new(self.dialect.as_ref());
+        let execute_sql = context.render_delete(&delete)?;
+        let execute_params = context.take_values();
+        let mut context = RdbcRenderContext::new(self.dialect.as_ref());
+        let query_sql = context.render_delete_returning_query(&self.delete)?;
+        Ok(RdbcReturningSql::QueryThenExecute {
+            query_sql,
+            query_params: context.take_values(),
+            execute_sql,
+            execute_params,
+        })
+    }
+}
+
 impl RdbcSqlRender for RdbcDeleteRender {
-    fn render_script(&self) -> (String, HashMap<String, RdbcValue>) {
-        todo!()
+    fn render_script(&self) -> Result<(String, HashMap<String, RdbcValue>), RdbcError> {
+        let mut context = RdbcRenderContext::new_named(self.dialect.as_ref());
+        let script = context.render_delete(&self.delete)?;
+        Ok((script, context.take_params()))
     }
 
-    fn render_sql(&self) -> (String, Vec<RdbcValue>) {
-        todo!()
+    fn render_sql(&self) -> Result<(String, Vec<RdbcValue>), RdbcError> {
+        let mut context = RdbcRenderContext::new(self.dialect.as_ref());
+        let sql = context.render_delete(&self.delete)?;
+        Ok((sql, context.take_values()))
     }
 
-    fn render_raw(&self) -> String {
-        todo!()
+    fn render_raw(&self) -> Result<String, RdbcError> {
+        RdbcRenderContext::new_raw(self.dialect.as_ref()).render_delete(&self.delete)
     }
-}
\ No newline at end of file
+}
+
+impl RdbcRenderContext<'_> {
+    pub(crate) fn render_delete(&mut self, delete: &RdbcDelete) -> Result<String, RdbcError> {
+        if delete.offset.is_some() {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "删除语句不支持 OFFSET".to_string(),
+            ));
+        }
+        let has_limit = !delete.order_by.is_empty() || delete.limit.is_some();
+        if has_limit && !delete.join_table.is_empty() {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "关联删除不支持 ORDER BY、LIMIT".to_string(),
+            ));
+        }
+        if has_limit && !self.dialect().support_delete_limit() {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "当前数据库删除语句不支持 ORDER BY、LIMIT".to_string(),
+            ));
+        }
+        let table = match delete.table.first() {
+            Some(table) => table,
+            None => {
+                return Err(RdbcError::new(
+                    RdbcErrKind::SQL,
+                    "删除语句未指定表".to_string(),
+                ))
+            }
+        };
+
+        let mut sql;
+        let mut conditions = vec![];
+        if delete.join_table.is_empty() {
+            sql = format!("DELETE FROM {}", self.render_table(table)?);
+        } else {
+            match self.dialect().delete_join_style() {
+                RdbcDeleteJoinStyle::Join => {
+                    let target = self.render_table_qualifier(table)?;
+                    sql = format!("DELETE {} FROM {}", target, self.render_table(table)?);
+                    for join_table in delete.join_table.iter() {
+                        sql.push_str(&format!(" {}", self.render_join_table(join_table)?));
+                    }
+                }
+                RdbcDeleteJoinStyle::Using => {
+                    sql = format!("DELETE FROM {}", self.render_table(table)?);
+                    // 首个关联表作为 USING 项，其连接条件并入 WHERE
+                    let first = &delete.join_table[0];
+                    if !matches!(first.join_type, JoinType::InnerJoin) {
+                        return Err(RdbcError::new(
+                            RdbcErrKind::SQL,
+                            "关联删除的首个关联表必须为内连接".to_string(),
+                        ));
+                    }
+                    sql.push_str(&format!(" USING {}", self.render_table(&first.table)?));
+                    for join_table in delete.join_table.iter().skip(1) {
+                        sql.push_str(&format!(" {}", self.render_join_table(join_table)?));
+                    }
+                    if let Some(filter) = first.filter.as_ref() {
+                        conditions.push(self.render_and_operand(filter)?);
+                    }
+                }
+                RdbcDeleteJoinStyle::Unsupported => {
+                    return Err(RdbcError::new(
+                        RdbcErrKind::SQL,
+                        "当前数据库不支持关联删除".to_string(),
+                    ))
+                }
+            }
+        }
+
+        let filter_sql = match delete.where_.as_ref() {
+            Some(filter) => self.render_and_operand(filter)?,
+            None => "".to_string(),
+        };
+        if filter_sql.is_empty() && !delete.allow_delete_all {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "删除语句未指定过滤条件，如需删除全表请显式开启 allow_delete_all".to_string(),
+            ));
+        }
+        conditions.push(filter_sql);
+        let conditions = conditions
+            .into_iter()
+            .filter(|condition| !condition.is_empty())
+            .collect::<Vec<String>>();
+        if !conditions.is_empty() {
+            sql.push_str(&format!(" WHERE {}", conditions.join(" AND ")));
+        }
+
+        if !delete.order_by.is_empty() {
+            let mut order_by = vec![];
+            for column in delete.order_by.iter() {
+                order_by.push(self.render_order_column(column)?);
+            }
+            sql.push_str(&format!(" ORDER BY {}", order_by.join(", ")));
+        }
+        if let Some(limit) = delete.limit {
+            sql.push_str(&format!(" LIMIT {}", limit));
+        }
+        sql.push_str(&self.render_returning(&delete.returning)?);
+        Ok(sql)
+    }
+
+    /// 按删除条件查询并锁定待删除的行
+    fn render_delete_returning_query(&mut self, delete: &RdbcDelete) -> Result<String, RdbcError> {
+        if !delete.join_table.is_empty() {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "当前数据库不支持 RETURNING，关联删除无法回查待删除行".to_string(),
+            ));
+        }
+        let table = match delete.table.first() {
+            Some(table) => self.render_table(table)?,
+            None => {
+                return Err(RdbcError::new(
+                    RdbcErrKind::SQL,
+                    "删除语句未指定表".to_string(),
+                ))
+            }
+        };
+        let mut sql = format!(
+            "SELECT {} FROM {}",
+            self.render_returning_columns(&delete.returning)?,
+            table
+        );
+        if let Some(filter) = delete.where_.as_ref() {
+            let filter_sql = self.render_and_operand(filter)?;
+            if !filter_sql.is_empty() {
+                sql.push_str(&format!(" WHERE {}", filter_sql));
+            }
+        }
+        if !delete.order_by.is_empty() {
+            let mut order_by = vec![];
+            for column in delete.order_by.iter() {
+                order_by.push(self.render_order_column(column)?);
+            }
+            sql.push_str(&format!(" ORDER BY {}", order_by.join(", ")));
+        }
+        if let Some(limit) = delete.limit {
+            sql.push_str(&format!(" LIMIT {}", limit));
+        }
+        if let Some(lock) =
+            self.dialect()
+                .lock_clause(&RdbcLockMode::Update, &[], &RdbcLockWait::Wait)
+        {
+            sql.push_str(&format!(" {}", lock));
+        }
+        Ok(sql)
+    }
+}
@@ -0,0 +1,242 @@
+use crate::render::dmq::render::RdbcRenderContext;
+use crate::{
+    RdbcCompare, RdbcFilterType, RdbcFilterValue, RdbcWhereCondition, RdbcWhereFilter,
+    RdbcWhereSimpleCondition,
+};
+use bmbp_rdbc_type::{RdbcErrKind, RdbcError, RdbcValue};
+
+impl RdbcRenderContext<'_> {
+    /// 渲染过滤条件，无有效条件时返回空串
+    pub(crate) fn render_filter(&mut self, filter: &RdbcWhereFilter) -> Result<String, RdbcError> {
+        let mut conditions = vec![];
+        for condition in filter.conditions.iter() {
+            let condition_sql = self.render_condition(condition)?;
+            if !condition_sql.is_empty() {
+                conditions.push(condition_sql);
+            }
+        }
+        match filter.type_ {
+            RdbcFilterType::And => Ok(conditions.join(" AND ")),
+            RdbcFilterType::Or => Ok(conditions.join(" OR ")),
+        }
+    }
+
+    /// 渲染作为 AND 操作数的过滤条件，OR 条件加括号
+    pub(crate) fn render_and_operand(
+        &mut self,
+        filter: &RdbcWhereFilter,
+    ) -> Result<String, RdbcError> {
+        let filter_sql = self.render_filter(filter)?;
+        match filter.type_ {
+            RdbcFilterType::Or if filter.conditions.len() > 1 => Ok(format!("({})", filter_sql)),
+            _ => Ok(filter_sql),
+        }
+    }
+
+    fn render_condition(&mut self, condition: &RdbcWhereCondition) -> Result<String, RdbcError> {
+        match condition {
+            RdbcWhereCondition::Simple(simple) => self.render_simple_condition(simple),
+            RdbcWhereCondition::Nest(nest) => {
+                let nest_sql = self.render_filter(&nest.condition)?;
+                if nest_sql.is_empty() {
+                    Ok(nest_sql)
+                } else {
+                    Ok(format!("({})", nest_sql))
+                }
+            }
+            RdbcWhereCondition::Raw(raw) => Ok(raw.condition.clone()),
+        }
+    }
+
+    fn render_simple_condition(
+        &mut self,
+        condition: &RdbcWhereSimpleCondition,
+    ) -> Result<String, RdbcError> {
+        let value = &condition.value;
+        match condition.compare {
+            RdbcCompare::Exists => {
+                return Ok(format!("EXISTS {}", self.render_exists_value(value)?));
+            }
+            RdbcCompare::NotExits => {
+                return Ok(format!("NOT EXISTS {}", self.render_exists_value(value)?));
+            }
+            _ => {}
+        }
+        let column = self.render_column(&condition.column)?;
+        self.set_param_hint(&condition.column);
+        if let RdbcFilterValue::Value(value) = value {
+            // NULL 及空串视为 NULL 的数据库，等值比较改写为 IS NULL
+            let is_null = matches!(value, RdbcValue::Null)
+                || (self.dialect().empty_string_as_null() && is_empty_string(value));
+            match condition.compare {
+                RdbcCompare::EQ if is_null => return Ok(format!("{} IS NULL", column)),
+                RdbcCompare::NE if is_null => return Ok(format!("{} IS NOT NULL", column)),
+                _ => {}
+            }
+        }
+        let condition_sql = match condition.compare {
+            RdbcCompare::EQ => format!("{} = {}", column, self.render_compare_value(value)?),
+            RdbcCompare::NE => format!("{} <> {}", column, self.render_compare_value(value)?),
+            RdbcCompare::GT => format!("{} > {}", column, self.render_compare_value(value)?),
+            RdbcCompare::GE => format!("{} >= {}", column, self.render_compare_value(value)?),
+            RdbcCompare::LT => format!("{} < {}", column, self.render_compare_value(value)?),
+            RdbcCompare::LE => format!("{} <= {}", column, self.render_compare_value(value)?),
+            RdbcCompare::Like => {
+                format!(
+                    "{} LIKE {}",
+                    column,
+                    self.render_like_value(value, true, true)?
+                )
+            }
+            RdbcCompare::LikeLeft => {
+                format!(
+                    "{} LIKE {}",
+                    column,
+                    self.render_like_value(value, true, false)?
+                )
+            }
+            RdbcCompare::LikeRight => {
+                format!(
+                    "{} LIKE {}",
+                    column,
+                    self.render_like_value(value, false, true)?
+                )
+            }
+            RdbcCompare::NotLike => {
+                format!(
+                    "{} NOT LIKE {}",
+                    column,
+                    self.render_like_value(value, true, true)?
+                )
+            }
+            RdbcCompare::NotLikeLeft => {
+                format!(
+                    "{} NOT LIKE {}",
+                    column,
+                    self.render_like_value(value, true, false)?
+                )
+            }
+            RdbcCompare::NotLikeRight => {
+                format!(
+                    "{} NOT LIKE {}",
+                    column,
+                    self.render_like_value(value, false, true)?
+                )
+            }
+            RdbcCompare::IN => match self.render_list_value(value)? {
+                Some(list) => format!("{} IN ({})", column, list),
+                None => "1 = 0".to_string(),
+            },
+            RdbcCompare::NotIn => match self.render_list_value(value)? {
+                Some(list) => format!("{} NOT IN ({})", column, list),
+                None => "1 = 1".to_string(),
+            },
+            RdbcCompare::IsNull => format!("{} IS NULL", column),
+            RdbcCompare::IsNotNull => format!("{} IS NOT NULL", column),
+            RdbcCompare::Between => {
+                format!("{} BETWEEN {}", column, self.render_between_value(value)?)
+            }
+            RdbcCompare::NotBetween => {
+                format!(
+                    "{} NOT BETWEEN {}",
+                    column,
+                    self.render_between_value(value)?
+                )
+            }
+            RdbcCompare::Exists | RdbcCompare::NotExits => unreachable!(),
+        };
+        Ok(condition_sql)
+    }
+
+    fn render_filter_value(&mut self, value: &RdbcFilterValue) -> Result<String, RdbcError> {
+        let value_sql = match value {
+            RdbcFilterValue::None => "".to_string(),
+            RdbcFilterValue::Value(value) => self.push_value(value)?,
+            RdbcFilterValue::Column(column) => self.render_column(column)?,
+            RdbcFilterValue::Query(query) => format!("({})", self.render_query(query)?),
+            RdbcFilterValue::Script(script) => self.push_script(script)?,
+            RdbcFilterValue::Raw(raw) => raw.clone(),
+        };
+        Ok(value_sql)
+    }
+
+    /// 比较运算的右值，不能为空
+    fn render_compare_value(&mut self, value: &RdbcFilterValue) -> Result<String, RdbcError> {
+        if let RdbcFilterValue::None = value {
+            return Err(RdbcError::new(RdbcErrKind::SQL, "比较条件缺少比较值".to_string()));
+        }
+        self.render_filter_value(value)
+    }
+
+    /// EXISTS 右值，仅支持子查询与原始语句
+    fn render_exists_value(&mut self, value: &RdbcFilterValue) -> Result<String, RdbcError> {
+        match value {
+            RdbcFilterValue::Query(query) => Ok(format!("({})", self.render_query(query)?)),
+            RdbcFilterValue::Raw(raw) => Ok(format!("({})", raw)),
+            _ => Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "EXISTS 条件仅支持子查询或原始语句".to_string(),
+            )),
+        }
+    }
+
+    /// IN 列表内容，数组值按元素逐个绑定，空数组返回 None
+    fn render_list_value(&mut self, value: &RdbcFilterValue) -> Result<Option<String>, RdbcError> {
+        match value {
+            RdbcFilterValue::Value(RdbcValue::Array(items)) if items.is_empty() => Ok(None),
+            RdbcFilterValue::Value(RdbcValue::Array(items)) => Ok(Some(
+                items
+                    .iter()
+                    .map(|item| self.push_value(item))
+                    .collect::<Result<Vec<String>, RdbcError>>()?
+                    .join(", "),
+            )),
+            RdbcFilterValue::Query(query) => Ok(Some(self.render_query(query)?)),
+            _ => Ok(Some(self.render_compare_value(value)?)),
+        }
+    }
+
+    /// BETWEEN 右值，需为两个元素的数组或原始语句
+    fn render_between_value(&mut self, value: &RdbcFilterValue) -> Result<String, RdbcError> {
+        match value {
+            RdbcFilterValue::Value(RdbcValue::Array(items)) if items.len() == 2 => {
+                let start = self.push_value(&items[0])?;
+                let end = self.push_value(&items[1])?;
+                Ok(format!("{} AND {}", start, end))
+            }
+            RdbcFilterValue::Raw(raw) => Ok(raw.clone()),
+            _ => Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "BETWEEN 条件需要两个元素的数组".to_string(),
+            )),
+        }
+    }
+
+    /// LIKE 右值，通配符在 SQL 中拼接，以便同样适用于列、脚本参数
+    fn render_like_value(
+        &mut self,
+        value: &RdbcFilterValue,
+        left: bool,
+        right: bool,
+    ) -> Result<String, RdbcError> {
+        let value_sql = self.render_compare_value(value)?;
+        let mut items = vec![];
+        if left {
+            items.push("'%'");
+        }
+        items.push(value_sql.as_str());
+        if right {
+            items.push("'%'");
+        }
+        Ok(self.concat(&items))
+    }
+}
+
+fn is_empty_string(value: &RdbcValue) -> bool {
+    match value {
+        RdbcValue::Varchar(value) | RdbcValue::Text(value) | RdbcValue::LongText(value) => {
+            value.is_empty()
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,223 @@
+use crate::render::dmq::render::RdbcRenderContext;
+use crate::{
+    RdbcColumn, RdbcFrameBound, RdbcFrameUnit, RdbcFunc, RdbcOffsetFunc, RdbcSumFuncColumn,
+    RdbcWindow, RdbcWindowFunc, RdbcWindowFuncType,
+};
+use bmbp_rdbc_type::{RdbcErrKind, RdbcError};
+
+impl RdbcRenderContext<'_> {
+    pub(crate) fn render_func(&mut self, func: &RdbcFunc) -> Result<String, RdbcError> {
+        let func_sql = match func {
+            RdbcFunc::DISTINCT(func) => {
+                format!("DISTINCT {}", self.render_column_list(&func.columns)?)
+            }
+            RdbcFunc::ABS(func) => format!("ABS({})", self.render_column_list(&func.column)?),
+            RdbcFunc::SUM(func) => {
+                let mut items = vec![];
+                for item in func.column.iter() {
+                    let item_sql = match item {
+                        RdbcSumFuncColumn::Column(column) => self.render_column(column)?,
+                        RdbcSumFuncColumn::Value(value) => self.push_value(value)?,
+                        RdbcSumFuncColumn::Raw(raw) => raw.clone(),
+                    };
+                    items.push(item_sql);
+                }
+                format!("SUM({})", items.join(", "))
+            }
+            RdbcFunc::COUNT(func) => match (func.column.is_empty(), func.distinct) {
+                (true, _) => "COUNT(*)".to_string(),
+                (false, true) => {
+                    let mut items = vec![];
+                    for column in func.column.iter() {
+                        items.push(self.render_column(column)?);
+                    }
+                    self.dialect().count_distinct(&items).ok_or_else(|| {
+                        RdbcError::new(
+                            RdbcErrKind::SQL,
+                            "当前数据库 COUNT(DISTINCT) 不支持多列".to_string(),
+                        )
+                    })?
+                }
+                (false, false) => format!("COUNT({})", self.render_column_list(&func.column)?),
+            },
+            RdbcFunc::MIN(func) => format!("MIN({})", self.render_column(&func.column)?),
+            RdbcFunc::MAX(func) => format!("MAX({})", self.render_column(&func.column)?),
+            RdbcFunc::AVG(func) => format!("AVG({})", self.render_column(&func.column)?),
+            RdbcFunc::StringAgg(func) => {
+                let column = self.render_column(&func.column)?;
+                let separator = self.dialect().string_literal(&func.separator);
+                self.dialect().string_agg(&column, &separator)
+            }
+            RdbcFunc::COALESCE(func) => {
+                format!("COALESCE({})", self.render_column_list(&func.args)?)
+            }
+            RdbcFunc::NULLIF(func) => {
+                if func.args.len() != 2 {
+                    return Err(RdbcError::new(
+                        RdbcErrKind::SQL,
+                        "NULLIF 需要两个参数".to_string(),
+                    ));
+                }
+                format!("NULLIF({})", self.render_column_list(&func.args)?)
+            }
+            RdbcFunc::CONCAT(func) => {
+                let mut items = vec![];
+                for arg in func.args.iter() {
+                    items.push(self.render_column(arg)?);
+                }
+                let items = items
+                    .iter()
+                    .map(|item| item.as_str())
+                    .collect::<Vec<&str>>();
+                format!("({})", self.concat(&items))
+            }
+            RdbcFunc::UPPER(func) => format!("UPPER({})", self.render_column(&func.column)?),
+            RdbcFunc::LOWER(func) => format!("LOWER({})", self.render_column(&func.column)?),
+            RdbcFunc::TRIM(func) => format!("TRIM({})", self.render_column(&func.column)?),
+            RdbcFunc::SUBSTRING(func) => {
+                let column = self.render_column(&func.column)?;
+                match func.length {
+                    Some(length) => format!("SUBSTR({}, {}, {})", column, func.start, length),
+                    None => format!("SUBSTR({}, {})", column, func.start),
+                }
+            }
+            RdbcFunc::LENGTH(func) => {
+                let column = self.render_column(&func.column)?;
+                self.dialect().length(&column)
+            }
+            RdbcFunc::REPLACE(func) => {
+                if func.args.len() != 3 {
+                    return Err(RdbcError::new(
+                        RdbcErrKind::SQL,
+                        "REPLACE 需要三个参数".to_string(),
+                    ));
+                }
+                format!("REPLACE({})", self.render_column_list(&func.args)?)
+            }
+            RdbcFunc::NOW => "CURRENT_TIMESTAMP".to_string(),
+            RdbcFunc::CurrentDate => "CURRENT_DATE".to_string(),
+            RdbcFunc::DateTrunc(func) => {
+                let column = self.render_column(&func.column)?;
+                self.dialect().date_trunc(&func.unit, &column)
+            }
+            RdbcFunc::EXTRACT(func) => {
+                let column = self.render_column(&func.column)?;
+                self.dialect().extract(&func.unit, &column)
+            }
+            RdbcFunc::DateAdd(func) => {
+                let column = self.render_column(&func.column)?;
+                self.dialect().date_add(&column, func.amount, &func.unit)
+            }
+            RdbcFunc::ROUND(func) => {
+                let column = self.render_column(&func.column)?;
+                match func.scale {
+                    Some(scale) => format!("ROUND({}, {})", column, scale),
+                    None => format!("ROUND({})", column),
+                }
+            }
+            RdbcFunc::FLOOR(func) => format!("FLOOR({})", self.render_column(&func.column)?),
+            RdbcFunc::CEIL(func) => format!("CEIL({})", self.render_column(&func.column)?),
+            RdbcFunc::WINDOW(func) => self.render_window_func(func)?,
+        };
+        Ok(func_sql)
+    }
+
+    fn render_window_func(&mut self, func: &RdbcWindowFunc) -> Result<String, RdbcError> {
+        if !self.dialect().support_window_func() {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "当前数据库不支持窗口函数".to_string(),
+            ));
+        }
+        let func_sql = match &func.func {
+            RdbcWindowFuncType::RowNumber => "ROW_NUMBER()".to_string(),
+            RdbcWindowFuncType::Rank => "RANK()".to_string(),
+            RdbcWindowFuncType::DenseRank => "DENSE_RANK()".to_string(),
+            RdbcWindowFuncType::Lag(func) => format!("LAG({})", self.render_offset_func(func)?),
+            RdbcWindowFuncType::Lead(func) => format!("LEAD({})", self.render_offset_func(func)?),
+            RdbcWindowFuncType::FirstValue(column) => {
+                format!("FIRST_VALUE({})", self.render_column(column)?)
+            }
+            RdbcWindowFuncType::LastValue(column) => {
+                format!("LAST_VALUE({})", self.render_column(column)?)
+            }
+            RdbcWindowFuncType::Aggregate(func) => self.render_func(func)?,
+        };
+        Ok(format!(
+            "{} OVER ({})",
+            func_sql,
+            self.render_window(&func.over)?
+        ))
+    }
+
+    fn render_offset_func(&mut self, func: &RdbcOffsetFunc) -> Result<String, RdbcError> {
+        let mut items = vec![self.render_column(&func.column)?];
+        match (func.offset, func.default.as_ref()) {
+            (Some(offset), Some(default)) => {
+                items.push(offset.to_string());
+                items.push(self.push_value(default)?);
+            }
+            (None, Some(default)) => {
+                items.push("1".to_string());
+                items.push(self.push_value(default)?);
+            }
+            (Some(offset), None) => items.push(offset.to_string()),
+            (None, None) => {}
+        }
+        Ok(items.join(", "))
+    }
+
+    pub(crate) fn render_window(&mut self, window: &RdbcWindow) -> Result<String, RdbcError> {
+        let mut items = vec![];
+        if !window.partition_by.is_empty() {
+            items.push(format!(
+                "PARTITION BY {}",
+                self.render_column_list(&window.partition_by)?
+            ));
+        }
+        if !window.order_by.is_empty() {
+            let mut order_by = vec![];
+            for column in window.order_by.iter() {
+                order_by.push(self.render_order_column(column)?);
+            }
+            items.push(format!("ORDER BY {}", order_by.join(", ")));
+        }
+        if let Some(frame) = window.frame.as_ref() {
+            let unit = match frame.unit {
+                RdbcFrameUnit::Rows => "ROWS",
+                RdbcFrameUnit::Range => "RANGE",
+            };
+            match frame.end.as_ref() {
+                Some(end) => items.push(format!(
+                    "{} BETWEEN {} AND {}",
+                    unit,
+                    render_frame_bound(&frame.start),
+                    render_frame_bound(end)
+                )),
+                None => items.push(format!("{} {}", unit, render_frame_bound(&frame.start))),
+            }
+        }
+        Ok(items.join(" "))
+    }
+
+    pub(crate) fn render_column_list(
+        &mut self,
+        columns: &[RdbcColumn],
+    ) -> Result<String, RdbcError> {
+        let mut items = vec![];
+        for column in columns.iter() {
+            items.push(self.render_column(column)?);
+        }
+        Ok(items.join(", "))
+    }
+}
+
+fn render_frame_bound(bound: &RdbcFrameBound) -> String {
+    match bound {
+        RdbcFrameBound::UnboundedPreceding => "UNBOUNDED PRECEDING".to_string(),
+        RdbcFrameBound::Preceding(rows) => format!("{} PRECEDING", rows),
+        RdbcFrameBound::CurrentRow => "CURRENT ROW".to_string(),
+        RdbcFrameBound::Following(rows) => format!("{} FOLLOWING", rows),
+        RdbcFrameBound::UnboundedFollowing => "UNBOUNDED FOLLOWING".to_string(),
+    }
+}
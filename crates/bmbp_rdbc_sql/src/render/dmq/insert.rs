@@ -1,20 +1,523 @@
+use crate::render::dmq::query::output_column_name;
+use crate::render::dmq::render::RdbcRenderContext;
+use crate::{
+    RdbcConflict, RdbcConflictAction, RdbcConflictColumn, RdbcConflictTarget, RdbcConflictValue,
+    RdbcDmlValue, RdbcInsert, RdbcPostgresDialect, RdbcReturningSql, RdbcSqlDialect, RdbcSqlRender,
+    RdbcTable, RdbcUpsertStyle,
+};
+use bmbp_rdbc_type::{RdbcErrKind, RdbcError, RdbcValue};
 use std::collections::HashMap;
-use bmbp_rdbc_type::RdbcValue;
-use crate::{RdbcInsert, RdbcSqlRender};
 
 pub struct RdbcInsertRender {
-    insert: RdbcInsert
+    insert: RdbcInsert,
+    dialect: Box<dyn RdbcSqlDialect>,
 }
+
+impl RdbcInsertRender {
+    pub fn new(insert: RdbcInsert) -> Self {
+        Self::new_with_dialect(insert, RdbcPostgresDialect)
+    }
+    pub fn new_with_dialect<D>(insert: RdbcInsert, dialect: D) -> Self
+    where
+        D: RdbcSqlDialect + 'static,
+    {
+        RdbcInsertRender {
+            insert,
+            dialect: Box::new(dialect),
+        }
+    }
+}
+
+impl RdbcInsertRender {
+    /// 渲染带返回行的插入，数据库不支持 RETURNING 时插入后按主键回查
+    pub fn render_returning_sql(&self) -> Result<RdbcReturningSql, RdbcError> {
+        if self.insert.returning.is_empty() {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "插入语句未指定返回列".to_string(),
+            ));
+        }
+        if self.dialect.support_returning() {
+            let (sql, values) = self.render_sql()?;
+            return Ok(RdbcReturningSql::Returning(sql, values));
+        }
+        let mut insert = self.insert.clone();
+        insert.returning.clear();
+        let mut context = RdbcRenderContext::new(self.dialect.as_ref());
+        let execute_sql = context.render_insert(&insert)?;
+        let execute_params = context.take_values();
+        let mut context = RdbcRenderContext::new(self.dialect.as_ref());
+        let query_sql = context.render_insert_returning_query(&self.insert)?;
+        Ok(RdbcReturningSql::ExecuteThenQuery {
+            execute_sql,
+            execute_params,
+            query_sql,
+            query_params: context.take_values(),
+        })
+    }
+}
+
 impl RdbcSqlRender for RdbcInsertRender {
-    fn render_script(&self) -> (String, HashMap<String, RdbcValue>) {
-        todo!()
+    fn render_script(&self) -> Result<(String, HashMap<String, RdbcValue>), RdbcError> {
+        let mut context = RdbcRenderContext::new_named(self.dialect.as_ref());
+        let script = context.render_insert(&self.insert)?;
+        Ok((script, context.take_params()))
+    }
+
+    fn render_sql(&self) -> Result<(String, Vec<RdbcValue>), RdbcError> {
+        let mut context = RdbcRenderContext::new(self.dialect.as_ref());
+        let sql = context.render_insert(&self.insert)?;
+        Ok((sql, context.take_values()))
+    }
+
+    fn render_raw(&self) -> Result<String, RdbcError> {
+        RdbcRenderContext::new_raw(self.dialect.as_ref()).render_insert(&self.insert)
+    }
+}
+
+/// 插入数据来源，已渲染的多行值或查询
+enum RdbcInsertSource {
+    Rows(Vec<Vec<String>>),
+    Query(String),
+}
+
+impl RdbcRenderContext<'_> {
+    pub(crate) fn render_insert(&mut self, insert: &RdbcInsert) -> Result<String, RdbcError> {
+        let table = match insert.table.first() {
+            Some(table) => table,
+            None => {
+                return Err(RdbcError::new(
+                    RdbcErrKind::SQL,
+                    "插入语句未指定表".to_string(),
+                ))
+            }
+        };
+        if let Some(conflict) = insert.conflict.as_ref() {
+            match self.dialect().upsert_style() {
+                RdbcUpsertStyle::Merge => return self.render_merge_insert(table, insert, conflict),
+                RdbcUpsertStyle::Unsupported => {
+                    return Err(RdbcError::new(
+                        RdbcErrKind::SQL,
+                        "当前数据库不支持插入冲突处理".to_string(),
+                    ))
+                }
+                _ => {}
+            }
+        }
+        let table = self.render_insert_table(table)?;
+        let (columns, source) = self.render_insert_source(insert)?;
+        let mut sql = format!("INSERT INTO {}", table);
+        if !columns.is_empty() {
+            sql.push_str(&format!(" ({})", columns.join(", ")));
+        }
+        match source {
+            RdbcInsertSource::Rows(rows) => {
+                let rows = rows
+                    .iter()
+                    .map(|row| format!("({})", row.join(", ")))
+                    .collect::<Vec<String>>();
+                sql.push_str(&format!(" VALUES {}", rows.join(", ")));
+            }
+            RdbcInsertSource::Query(query) => sql.push_str(&format!(" {}", query)),
+        }
+        if let Some(conflict) = insert.conflict.as_ref() {
+            sql.push_str(&self.render_conflict(conflict, &columns)?);
+        }
+        sql.push_str(&self.render_returning(&insert.returning)?);
+        Ok(sql)
+    }
+
+    /// 渲染插入列与插入来源
+    fn render_insert_source(
+        &mut self,
+        insert: &RdbcInsert,
+    ) -> Result<(Vec<String>, RdbcInsertSource), RdbcError> {
+        if !insert.column_value.is_empty() {
+            if !insert.column.is_empty() || !insert.values.is_empty() || insert.query.is_some() {
+                return Err(RdbcError::new(
+                    RdbcErrKind::SQL,
+                    "插入语句不能同时指定列值对与插入列".to_string(),
+                ));
+            }
+            let mut columns = vec![];
+            let mut values = vec![];
+            for column_value in insert.column_value.iter() {
+                columns.push(self.render_column(&column_value.column)?);
+                self.set_param_hint(&column_value.column);
+                values.push(self.render_dml_value(&column_value.value)?);
+            }
+            return Ok((columns, RdbcInsertSource::Rows(vec![values])));
+        }
+
+        let columns = insert
+            .column
+            .iter()
+            .map(|column| self.quote_ident(column))
+            .collect::<Result<Vec<String>, RdbcError>>()?;
+        let source = match (insert.query.as_ref(), insert.values.is_empty()) {
+            (Some(query), true) => {
+                let query_sql = self.render_query(query)?;
+                let has_where = query.compound.is_empty()
+                    && query
+                        .where_
+                        .as_ref()
+                        .is_some_and(|filter| !filter.conditions.is_empty());
+                if insert.conflict.is_some()
+                    && !has_where
+                    && self.dialect().conflict_select_need_where()
+                {
+                    RdbcInsertSource::Query(format!("SELECT * FROM ({}) WHERE true", query_sql))
+                } else {
+                    RdbcInsertSource::Query(query_sql)
+                }
+            }
+            (None, false) => {
+                let mut rows = vec![];
+                for row in insert.values.iter() {
+                    if !insert.column.is_empty() && row.len() != insert.column.len() {
+                        return Err(RdbcError::new(
+                            RdbcErrKind::SQL,
+                            format!(
+                                "插入值个数{}与插入列个数{}不一致",
+                                row.len(),
+                                insert.column.len()
+                            ),
+                        ));
+                    }
+                    let mut values = vec![];
+                    for (index, value) in row.iter().enumerate() {
+                        if let Some(column) = insert.column.get(index) {
+                            self.set_param_hint_name(column);
+                        }
+                        values.push(self.render_dml_value(value)?);
+                    }
+                    rows.push(values);
+                }
+                RdbcInsertSource::Rows(rows)
+            }
+            (Some(_), false) => {
+                return Err(RdbcError::new(
+                    RdbcErrKind::SQL,
+                    "插入语句不能同时指定插入值与查询".to_string(),
+                ))
+            }
+            (None, true) => {
+                return Err(RdbcError::new(
+                    RdbcErrKind::SQL,
+                    "插入语句未指定插入值".to_string(),
+                ))
+            }
+        };
+        Ok((columns, source))
+    }
+
+    /// ON CONFLICT 或 ON DUPLICATE KEY UPDATE 子句
+    fn render_conflict(
+        &mut self,
+        conflict: &RdbcConflict,
+        columns: &[String],
+    ) -> Result<String, RdbcError> {
+        if self.dialect().upsert_style() == RdbcUpsertStyle::OnDuplicateKey {
+            // MySQL 按任一唯一键判断冲突，忽略冲突行以更新首列为自身实现
+            let set = match &conflict.action {
+                RdbcConflictAction::Nothing => match columns.first() {
+                    Some(column) => vec![format!("{} = {}", column, column)],
+                    None => {
+                        return Err(RdbcError::new(
+                            RdbcErrKind::SQL,
+                            "忽略冲突行需要指定插入列".to_string(),
+                        ))
+                    }
+                },
+                RdbcConflictAction::Update(set) => {
+                    self.render_conflict_set(set, "", |column| format!("VALUES({})", column))?
+                }
+            };
+            return Ok(format!(" ON DUPLICATE KEY UPDATE {}", set.join(", ")));
+        }
+
+        let mut sql = " ON CONFLICT".to_string();
+        match &conflict.target {
+            Some(RdbcConflictTarget::Columns(target)) => {
+                let target = target
+                    .iter()
+                    .map(|column| self.quote_ident(column))
+                    .collect::<Result<Vec<String>, RdbcError>>()?;
+                sql.push_str(&format!(" ({})", target.join(", ")));
+            }
+            Some(RdbcConflictTarget::Constraint(constraint)) => {
+                if !self.dialect().support_conflict_constraint() {
+                    return Err(RdbcError::new(
+                        RdbcErrKind::SQL,
+                        "当前数据库不支持按约束名判断冲突".to_string(),
+                    ));
+                }
+                sql.push_str(&format!(" ON CONSTRAINT {}", self.quote_ident(constraint)?));
+            }
+            None => {
+                if let RdbcConflictAction::Update(_) = conflict.action {
+                    return Err(RdbcError::new(
+                        RdbcErrKind::SQL,
+                        "冲突更新需要指定冲突列或约束".to_string(),
+                    ));
+                }
+            }
+        }
+        match &conflict.action {
+            RdbcConflictAction::Nothing => sql.push_str(" DO NOTHING"),
+            RdbcConflictAction::Update(set) => {
+                let set =
+                    self.render_conflict_set(set, "", |column| format!("EXCLUDED.{}", column))?;
+                sql.push_str(&format!(" DO UPDATE SET {}", set.join(", ")));
+            }
+        }
+        Ok(sql)
     }
 
-    fn render_sql(&self) -> (String, Vec<RdbcValue>) {
-        todo!()
+    /// 插入目标表，别名须写作 AS alias，仅部分数据库支持
+    fn render_insert_table(&mut self, table: &RdbcTable) -> Result<String, RdbcError> {
+        let alias = match table {
+            RdbcTable::SimpleTable(table) => &table.alias,
+            RdbcTable::QueryTable(table) => &table.alias,
+            RdbcTable::RawTable(table) => &table.alias,
+        };
+        if alias.is_empty() {
+            return self.render_table(table);
+        }
+        if !self.dialect().support_insert_alias() {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "当前数据库插入语句不支持表别名".to_string(),
+            ));
+        }
+        let table_sql = match table {
+            RdbcTable::SimpleTable(table) => self.render_simple_table_name(table)?,
+            RdbcTable::RawTable(table) => table.table.clone(),
+            RdbcTable::QueryTable(_) => {
+                return Err(RdbcError::new(
+                    RdbcErrKind::SQL,
+                    "插入语句的目标不能为子查询".to_string(),
+                ))
+            }
+        };
+        Ok(format!("{} AS {}", table_sql, self.quote_ident(alias)?))
     }
 
-    fn render_raw(&self) -> String {
-        todo!()
+    /// Oracle 以 MERGE 实现插入冲突处理，待插入行作为 USING 子查询 s_
+    fn render_merge_insert(
+        &mut self,
+        table: &RdbcTable,
+        insert: &RdbcInsert,
+        conflict: &RdbcConflict,
+    ) -> Result<String, RdbcError> {
+        let (table, alias) = match table {
+            RdbcTable::SimpleTable(table) => {
+                let alias = if table.alias.is_empty() {
+                    "t_".to_string()
+                } else {
+                    self.quote_ident(&table.alias)?
+                };
+                (self.render_simple_table_name(table)?, alias)
+            }
+            _ => {
+                return Err(RdbcError::new(
+                    RdbcErrKind::SQL,
+                    "MERGE 仅支持普通表".to_string(),
+                ))
+            }
+        };
+        if !insert.returning.is_empty() {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "MERGE 不支持 RETURNING".to_string(),
+            ));
+        }
+        let keys = match &conflict.target {
+            Some(RdbcConflictTarget::Columns(keys)) => keys
+                .iter()
+                .map(|key| self.quote_ident(key))
+                .collect::<Result<Vec<String>, RdbcError>>()?,
+            _ => {
+                return Err(RdbcError::new(
+                    RdbcErrKind::SQL,
+                    "MERGE 需要指定冲突列".to_string(),
+                ))
+            }
+        };
+        if insert.query.is_some() {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "MERGE 暂不支持以查询作为插入来源".to_string(),
+            ));
+        }
+        let (columns, source) = self.render_insert_source(insert)?;
+        if columns.is_empty() {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "MERGE 需要指定插入列".to_string(),
+            ));
+        }
+        if let Some(key) = keys.iter().find(|key| !columns.contains(key)) {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                format!("冲突列{}不在插入列中", key),
+            ));
+        }
+        let rows = match source {
+            RdbcInsertSource::Rows(rows) => rows,
+            RdbcInsertSource::Query(_) => vec![],
+        };
+        let source = rows
+            .iter()
+            .map(|row| {
+                let items = row
+                    .iter()
+                    .zip(columns.iter())
+                    .map(|(value, column)| format!("{} AS {}", value, column))
+                    .collect::<Vec<String>>();
+                format!("SELECT {} FROM DUAL", items.join(", "))
+            })
+            .collect::<Vec<String>>();
+        let on = keys
+            .iter()
+            .map(|key| format!("{}.{} = s_.{}", alias, key, key))
+            .collect::<Vec<String>>();
+        let mut sql = format!(
+            "MERGE INTO {} {} USING ({}) s_ ON ({})",
+            table,
+            alias,
+            source.join(" UNION ALL "),
+            on.join(" AND ")
+        );
+        if let RdbcConflictAction::Update(set) = &conflict.action {
+            for column in set.iter() {
+                let column = self.quote_ident(&column.column)?;
+                if keys.contains(&column) {
+                    return Err(RdbcError::new(
+                        RdbcErrKind::SQL,
+                        format!("MERGE 不能更新冲突列{}", column),
+                    ));
+                }
+            }
+            let prefix = format!("{}.", alias);
+            let set = self.render_conflict_set(set, &prefix, |column| format!("s_.{}", column))?;
+            sql.push_str(&format!(" WHEN MATCHED THEN UPDATE SET {}", set.join(", ")));
+        }
+        let values = columns
+            .iter()
+            .map(|column| format!("s_.{}", column))
+            .collect::<Vec<String>>();
+        sql.push_str(&format!(
+            " WHEN NOT MATCHED THEN INSERT ({}) VALUES ({})",
+            columns.join(", "),
+            values.join(", ")
+        ));
+        Ok(sql)
     }
-}
\ No newline at end of file
+
+    /// 按主键回查插入行，主键取显式插入的值，否则取数据库最后生成的自增值
+    fn render_insert_returning_query(&mut self, insert: &RdbcInsert) -> Result<String, RdbcError> {
+        let table = match insert.table.first() {
+            Some(RdbcTable::SimpleTable(table)) => self.render_simple_table_name(table)?,
+            _ => {
+                return Err(RdbcError::new(
+                    RdbcErrKind::SQL,
+                    "回查插入行仅支持普通表".to_string(),
+                ))
+            }
+        };
+        let key = match insert.returning_key.as_ref() {
+            Some(key) => key,
+            None => {
+                return Err(RdbcError::new(
+                    RdbcErrKind::SQL,
+                    "当前数据库不支持 RETURNING，需通过 returning_key 指定主键列".to_string(),
+                ))
+            }
+        };
+        let row = if !insert.column_value.is_empty() {
+            insert
+                .column_value
+                .iter()
+                .filter_map(|column_value| {
+                    output_column_name(&column_value.column, "")
+                        .map(|column| (column, &column_value.value))
+                })
+                .collect::<Vec<(String, &RdbcDmlValue)>>()
+        } else if insert.query.is_none() && insert.values.len() == 1 {
+            insert
+                .column
+                .iter()
+                .cloned()
+                .zip(insert.values[0].iter())
+                .collect::<Vec<(String, &RdbcDmlValue)>>()
+        } else {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "当前数据库不支持 RETURNING，仅支持回查单行插入".to_string(),
+            ));
+        };
+        let key_value = match row.iter().find(|(column, _)| column == key) {
+            Some((_, RdbcDmlValue::VALUE(value))) => {
+                self.set_param_hint_name(key);
+                self.push_value(value)?
+            }
+            Some(_) => {
+                return Err(RdbcError::new(
+                    RdbcErrKind::SQL,
+                    format!("主键列{}的插入值不是常量，无法回查插入行", key),
+                ))
+            }
+            None => match self.dialect().last_insert_id() {
+                Some(last_insert_id) if insert.conflict.is_none() => last_insert_id.to_string(),
+                _ => {
+                    return Err(RdbcError::new(
+                        RdbcErrKind::SQL,
+                        format!("未插入主键列{}的值，无法回查插入行", key),
+                    ))
+                }
+            },
+        };
+        Ok(format!(
+            "SELECT {} FROM {} WHERE {} = {}",
+            self.render_returning_columns(&insert.returning)?,
+            table,
+            self.quote_ident(key)?,
+            key_value
+        ))
+    }
+
+    /// 冲突更新的赋值列表，excluded 渲染对待插入行同名列的引用
+    fn render_conflict_set<F>(
+        &mut self,
+        set: &[RdbcConflictColumn],
+        prefix: &str,
+        excluded: F,
+    ) -> Result<Vec<String>, RdbcError>
+    where
+        F: Fn(&str) -> String,
+    {
+        let mut items = vec![];
+        for column in set.iter() {
+            let name = self.quote_ident(&column.column)?;
+            let value = match &column.value {
+                RdbcConflictValue::Excluded(source) => excluded(&self.quote_ident(source)?),
+                RdbcConflictValue::Value(value) => {
+                    self.set_param_hint_name(&column.column);
+                    self.render_dml_value(value)?
+                }
+            };
+            items.push(format!("{}{} = {}", prefix, name, value));
+        }
+        Ok(items)
+    }
+
+    /// 写入值，普通值绑定为参数，列与函数作为表达式输出
+    pub(crate) fn render_dml_value(&mut self, value: &RdbcDmlValue) -> Result<String, RdbcError> {
+        match value {
+            RdbcDmlValue::VALUE(value) => self.push_value(value),
+            RdbcDmlValue::COLUMN(column) => self.render_column(column),
+            RdbcDmlValue::FUNC(func) => self.render_func(func),
+        }
+    }
+}
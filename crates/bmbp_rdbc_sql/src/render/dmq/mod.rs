@@ -3,9 +3,15 @@ mod render;
 mod update;
 mod delete;
 mod insert;
+mod script;
+mod column;
+mod filter;
+mod func;
+mod table;
 
 pub use query::*;
 pub use render::*;
 pub use update::*;
 pub use delete::*;
 pub use insert::*;
+pub use script::*;
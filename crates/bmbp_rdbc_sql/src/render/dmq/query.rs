@@ -1,5 +1,352 @@
-use crate::RdbcQuery;
+use crate::render::dmq::render::RdbcRenderContext;
+use crate::{
+    compound_keyword, lock_clause, RdbcColumn, RdbcLock, RdbcOrderColumn, RdbcOrderType,
+    RdbcPostgresDialect, RdbcQuery, RdbcSqlDialect, RdbcSqlRender, RdbcWithTable,
+};
+use bmbp_rdbc_type::{RdbcErrKind, RdbcError, RdbcValue};
+use std::collections::HashMap;
 
 pub struct RdbcQueryRender {
     query: RdbcQuery,
+    dialect: Box<dyn RdbcSqlDialect>,
+}
+
+impl RdbcQueryRender {
+    pub fn new(query: RdbcQuery) -> Self {
+        Self::new_with_dialect(query, RdbcPostgresDialect)
+    }
+    pub fn new_with_dialect<D>(query: RdbcQuery, dialect: D) -> Self
+    where
+        D: RdbcSqlDialect + 'static,
+    {
+        RdbcQueryRender {
+            query,
+            dialect: Box::new(dialect),
+        }
+    }
+}
+
+impl RdbcSqlRender for RdbcQueryRender {
+    fn render_script(&self) -> Result<(String, HashMap<String, RdbcValue>), RdbcError> {
+        let mut context = RdbcRenderContext::new_named(self.dialect.as_ref());
+        let script = context.render_query(&self.query)?;
+        Ok((script, context.take_params()))
+    }
+
+    fn render_sql(&self) -> Result<(String, Vec<RdbcValue>), RdbcError> {
+        let mut context = RdbcRenderContext::new(self.dialect.as_ref());
+        let sql = context.render_query(&self.query)?;
+        Ok((sql, context.take_values()))
+    }
+
+    fn render_raw(&self) -> Result<String, RdbcError> {
+        RdbcRenderContext::new_raw(self.dialect.as_ref()).render_query(&self.query)
+    }
+}
+
+impl RdbcRenderContext<'_> {
+    pub(crate) fn render_query(&mut self, query: &RdbcQuery) -> Result<String, RdbcError> {
+        let with_sql = self.render_with(&query.with)?;
+        let query_sql = self.render_compound_query(query)?;
+        Ok(format!("{}{}", with_sql, query_sql))
+    }
+
+    /// 渲染 WITH 子句，含尾部空格，无公共表表达式时返回空串
+    fn render_with(&mut self, with: &[RdbcWithTable]) -> Result<String, RdbcError> {
+        if with.is_empty() {
+            return Ok("".to_string());
+        }
+        let recursive = with.iter().any(|table| table.recursive);
+        let mut tables = vec![];
+        for table in with.iter() {
+            if table.recursive
+                && table.columns.is_empty()
+                && !self.dialect().with_recursive_keyword()
+            {
+                return Err(RdbcError::new(
+                    RdbcErrKind::SQL,
+                    format!("递归查询{}需要声明列名", table.name),
+                ));
+            }
+            let mut table_sql = self.quote_ident(&table.name)?;
+            if !table.columns.is_empty() {
+                let columns = table
+                    .columns
+                    .iter()
+                    .map(|column| self.quote_ident(column))
+                    .collect::<Result<Vec<String>, RdbcError>>()?;
+                table_sql.push_str(&format!(" ({})", columns.join(", ")));
+            }
+            table_sql.push_str(&format!(" AS ({})", self.render_query(&table.query)?));
+            tables.push(table_sql);
+        }
+        if recursive && self.dialect().with_recursive_keyword() {
+            Ok(format!("WITH RECURSIVE {} ", tables.join(", ")))
+        } else {
+            Ok(format!("WITH {} ", tables.join(", ")))
+        }
+    }
+
+    fn render_compound_query(&mut self, query: &RdbcQuery) -> Result<String, RdbcError> {
+        let query_sql = self.render_query_body(query)?;
+        if query.compound.is_empty() {
+            if !query.compound_order_by.is_empty()
+                || query.compound_limit.is_some()
+                || query.compound_offset.is_some()
+            {
+                return Err(RdbcError::new(
+                    RdbcErrKind::SQL,
+                    "未指定集合查询，排序与分页请直接设置在查询上".to_string(),
+                ));
+            }
+            return Ok(query_sql);
+        }
+        if query.lock.is_some() {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "集合查询不支持行锁".to_string(),
+            ));
+        }
+        let first_nested =
+            !query.order_by.is_empty() || query.limit.is_some() || query.offset.is_some();
+        let mut sql = self.render_compound_member(query_sql, first_nested);
+        for compound in query.compound.iter() {
+            let keyword = self
+                .dialect()
+                .compound_keyword(&compound.compound_type)
+                .ok_or_else(|| {
+                    RdbcError::new(
+                        RdbcErrKind::SQL,
+                        format!(
+                            "当前数据库不支持集合查询{}",
+                            compound_keyword(&compound.compound_type)
+                        ),
+                    )
+                })?;
+            let member = &compound.query;
+            let nested = !member.with.is_empty()
+                || !member.compound.is_empty()
+                || !member.order_by.is_empty()
+                || member.limit.is_some()
+                || member.offset.is_some();
+            let member_sql = self.render_query(member)?;
+            sql.push_str(&format!(
+                " {} {}",
+                keyword,
+                self.render_compound_member(member_sql, nested)
+            ));
+        }
+        sql.push_str(&self.render_order_by(&query.compound_order_by)?);
+        Ok(self.render_page(sql, query.compound_limit, query.compound_offset))
+    }
+
+    /// 集合查询的成员加括号，不支持括号的数据库对带排序、分页或嵌套集合的成员使用子查询包装
+    fn render_compound_member(&self, member_sql: String, nested: bool) -> String {
+        if self.dialect().support_compound_paren() {
+            format!("({})", member_sql)
+        } else if nested {
+            format!("SELECT * FROM ({})", member_sql)
+        } else {
+            member_sql
+        }
+    }
+
+    fn render_query_body(&mut self, query: &RdbcQuery) -> Result<String, RdbcError> {
+        if !query.distinct_on.is_empty() && !self.dialect().support_distinct_on() {
+            return self.render_distinct_on_query(query);
+        }
+        let mut sql = "SELECT ".to_string();
+        if !query.distinct_on.is_empty() {
+            let mut distinct_on = vec![];
+            for column in query.distinct_on.iter() {
+                distinct_on.push(self.render_column(column)?);
+            }
+            sql.push_str(&format!("DISTINCT ON ({}) ", distinct_on.join(", ")));
+        } else if query.distinct {
+            sql.push_str("DISTINCT ");
+        }
+        sql.push_str(&self.render_select_list(query)?);
+        sql.push_str(&self.render_query_from(query)?);
+        sql.push_str(&self.render_order_by(&query.order_by)?);
+        self.render_page_lock(sql, query)
+    }
+
+    /// 不支持 DISTINCT ON 的数据库，按去重列分区取 ROW_NUMBER 为 1 的行
+    fn render_distinct_on_query(&mut self, query: &RdbcQuery) -> Result<String, RdbcError> {
+        if !self.dialect().support_window_func() {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "当前数据库不支持 DISTINCT ON，且无法使用窗口函数改写".to_string(),
+            ));
+        }
+        if query.select.is_empty() {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "DISTINCT ON 改写需要明确查询列".to_string(),
+            ));
+        }
+        let mut outer_select = vec![];
+        for column in query.select.iter() {
+            let name = output_column_name(&column.column, &column.alias).ok_or_else(|| {
+                RdbcError::new(
+                    RdbcErrKind::SQL,
+                    "DISTINCT ON 改写需要为表达式列指定别名".to_string(),
+                )
+            })?;
+            outer_select.push(self.quote_ident(&name)?);
+        }
+        // 排序列随内层查询输出，外层按其别名排序
+        let mut select_sql = self.render_select_list(query)?;
+        let mut outer_order = vec![];
+        for (index, column) in query.order_by.iter().enumerate() {
+            let alias = format!("o{}_", index + 1);
+            select_sql.push_str(&format!(
+                ", {} AS {}",
+                self.render_column(&column.column)?,
+                alias
+            ));
+            outer_order.push(match column.order_type {
+                RdbcOrderType::Asc => format!("{} ASC", alias),
+                RdbcOrderType::Desc => format!("{} DESC", alias),
+            });
+        }
+
+        let mut sql = format!("SELECT {}, ", select_sql);
+        let mut partition = vec![];
+        for column in query.distinct_on.iter() {
+            partition.push(self.render_column(column)?);
+        }
+        sql.push_str(&format!(
+            "ROW_NUMBER() OVER (PARTITION BY {}{}) AS rn_",
+            partition.join(", "),
+            self.render_order_by(&query.order_by)?
+        ));
+        sql.push_str(&self.render_query_from(query)?);
+
+        let mut sql = format!(
+            "SELECT {} FROM ({}) t_ WHERE rn_ = 1",
+            outer_select.join(", "),
+            sql
+        );
+        if !outer_order.is_empty() {
+            sql.push_str(&format!(" ORDER BY {}", outer_order.join(", ")));
+        }
+        self.render_page_lock(sql, query)
+    }
+
+    fn render_select_list(&mut self, query: &RdbcQuery) -> Result<String, RdbcError> {
+        if query.select.is_empty() {
+            return Ok("*".to_string());
+        }
+        let mut select = vec![];
+        for column in query.select.iter() {
+            select.push(self.render_select_column(column)?);
+        }
+        Ok(select.join(", "))
+    }
+
+    /// FROM、JOIN、WHERE、GROUP BY、HAVING 子句，含前导空格
+    fn render_query_from(&mut self, query: &RdbcQuery) -> Result<String, RdbcError> {
+        let mut sql = "".to_string();
+        if !query.table.is_empty() {
+            let mut tables = vec![];
+            for table in query.table.iter() {
+                tables.push(self.render_table(table)?);
+            }
+            sql.push_str(&format!(" FROM {}", tables.join(", ")));
+        }
+        for join_table in query.join_table.iter() {
+            let join_sql = self.render_join_table(join_table)?;
+            sql.push_str(&format!(" {}", join_sql));
+        }
+
+        if let Some(filter) = query.where_.as_ref() {
+            let filter_sql = self.render_filter(filter)?;
+            if !filter_sql.is_empty() {
+                sql.push_str(&format!(" WHERE {}", filter_sql));
+            }
+        }
+
+        if !query.group_by.is_empty() {
+            let mut group_by = vec![];
+            for column in query.group_by.iter() {
+                group_by.push(self.render_group_column(column)?);
+            }
+            sql.push_str(&format!(" GROUP BY {}", group_by.join(", ")));
+        }
+        if let Some(filter) = query
+            .having
+            .as_ref()
+            .and_then(|having| having.filter.as_ref())
+        {
+            let having_sql = self.render_filter(filter)?;
+            if !having_sql.is_empty() {
+                sql.push_str(&format!(" HAVING {}", having_sql));
+            }
+        }
+        Ok(sql)
+    }
+
+    /// ORDER BY 子句，含前导空格，无排序时返回空串
+    fn render_order_by(&mut self, order_by: &[RdbcOrderColumn]) -> Result<String, RdbcError> {
+        if order_by.is_empty() {
+            return Ok("".to_string());
+        }
+        let mut columns = vec![];
+        for column in order_by.iter() {
+            columns.push(self.render_order_column(column)?);
+        }
+        Ok(format!(" ORDER BY {}", columns.join(", ")))
+    }
+
+    /// 分页与行锁，部分数据库不允许分页查询加锁
+    fn render_page_lock(&mut self, sql: String, query: &RdbcQuery) -> Result<String, RdbcError> {
+        let paged = query.limit.is_some() || query.offset.is_some();
+        let mut sql = self.render_page(sql, query.limit, query.offset);
+        if let Some(lock) = query.lock.as_ref() {
+            if paged && !self.dialect().support_lock_with_page() {
+                return Err(RdbcError::new(
+                    RdbcErrKind::SQL,
+                    "当前数据库不支持分页查询加行锁".to_string(),
+                ));
+            }
+            sql.push_str(&format!(" {}", self.render_lock(lock)?));
+        }
+        Ok(sql)
+    }
+    fn render_lock(&mut self, lock: &RdbcLock) -> Result<String, RdbcError> {
+        let of = lock
+            .of
+            .iter()
+            .map(|table| self.quote_ident(table))
+            .collect::<Result<Vec<String>, RdbcError>>()?;
+        self.dialect()
+            .lock_clause(&lock.mode, &of, &lock.wait)
+            .ok_or_else(|| {
+                RdbcError::new(
+                    RdbcErrKind::SQL,
+                    format!(
+                        "当前数据库不支持行锁{}",
+                        lock_clause(&lock.mode, &of, &lock.wait)
+                    ),
+                )
+            })
+    }
+}
+
+/// 子查询外层引用的列名，优先使用别名，其次为不带表限定的列名
+pub(crate) fn output_column_name(column: &RdbcColumn, alias: &str) -> Option<String> {
+    if !alias.is_empty() {
+        return Some(alias.to_string());
+    }
+    match column {
+        RdbcColumn::SimpleColumn(column) => column
+            .column
+            .rsplit('.')
+            .next()
+            .filter(|name| *name != "*")
+            .map(|name| name.to_string()),
+        RdbcColumn::TableColumn(column) => Some(column.column.clone()),
+        _ => None,
+    }
 }
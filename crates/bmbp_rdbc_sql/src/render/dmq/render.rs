@@ -1,16 +1,226 @@
+use crate::{split_ident, JoinType, RdbcColumn, RdbcSqlDialect};
+use bmbp_rdbc_type::{RdbcErrKind, RdbcError, RdbcValue};
 use std::collections::HashMap;
-use bmbp_rdbc_type::RdbcValue;
 
-pub trait RdbcSqlRender{
-    fn render_script(&self) -> (String, HashMap<String, RdbcValue>) {
-        ("".to_string(), HashMap::new())
+pub trait RdbcSqlRender {
+    fn render_script(&self) -> Result<(String, HashMap<String, RdbcValue>), RdbcError> {
+        Ok(("".to_string(), HashMap::new()))
     }
 
-    fn render_sql(&self) -> (String, Vec<RdbcValue>) {
-        ("".to_string(), vec![])
+    fn render_sql(&self) -> Result<(String, Vec<RdbcValue>), RdbcError> {
+        Ok(("".to_string(), vec![]))
     }
 
-    fn render_raw(&self) -> String {
-        "".to_string()
+    fn render_raw(&self) -> Result<String, RdbcError> {
+        Ok("".to_string())
     }
-}
\ No newline at end of file
+}
+
+/// 带返回行的 DML 执行方式，回查语句须与 DML 在同一连接上执行
+#[derive(Debug, Clone)]
+pub enum RdbcReturningSql {
+    /// 原生 RETURNING，执行语句即返回受影响的行
+    Returning(String, Vec<RdbcValue>),
+    /// 先执行语句，再回查插入的行
+    ExecuteThenQuery {
+        execute_sql: String,
+        execute_params: Vec<RdbcValue>,
+        query_sql: String,
+        query_params: Vec<RdbcValue>,
+    },
+    /// 先查询并锁定待删除的行，再执行语句
+    QueryThenExecute {
+        query_sql: String,
+        query_params: Vec<RdbcValue>,
+        execute_sql: String,
+        execute_params: Vec<RdbcValue>,
+    },
+}
+
+/// 参数的渲染方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RdbcRenderMode {
+    /// 方言占位符，按顺序收集绑定参数
+    Positional,
+    /// 命名参数脚本 #{name}
+    Named,
+    /// 按方言转义的字面量
+    Raw,
+}
+
+/// 渲染上下文，按渲染顺序收集绑定参数
+pub(crate) struct RdbcRenderContext<'a> {
+    dialect: &'a dyn RdbcSqlDialect,
+    values: Vec<RdbcValue>,
+    mode: RdbcRenderMode,
+    params: HashMap<String, RdbcValue>,
+    param_hint: String,
+    param_index: HashMap<String, usize>,
+}
+
+impl<'a> RdbcRenderContext<'a> {
+    pub(crate) fn new(dialect: &'a dyn RdbcSqlDialect) -> Self {
+        RdbcRenderContext {
+            dialect,
+            values: vec![],
+            mode: RdbcRenderMode::Positional,
+            params: HashMap::new(),
+            param_hint: "".to_string(),
+            param_index: HashMap::new(),
+        }
+    }
+    /// 命名参数模式，参数渲染为 #{p_列名_序号}
+    pub(crate) fn new_named(dialect: &'a dyn RdbcSqlDialect) -> Self {
+        RdbcRenderContext {
+            mode: RdbcRenderMode::Named,
+            ..Self::new(dialect)
+        }
+    }
+    /// 字面量模式，参数直接转义写入 SQL
+    pub(crate) fn new_raw(dialect: &'a dyn RdbcSqlDialect) -> Self {
+        RdbcRenderContext {
+            mode: RdbcRenderMode::Raw,
+            ..Self::new(dialect)
+        }
+    }
+    /// 引用脚本参数 #{name}，仅命名参数模式可用
+    pub(crate) fn push_script(&mut self, name: &str) -> Result<String, RdbcError> {
+        if self.mode != RdbcRenderMode::Named {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                format!(
+                    "脚本参数#{{{}}}需使用 render_script 渲染，并由 RdbcScriptRender 绑定",
+                    name
+                ),
+            ));
+        }
+        Ok(format!("#{{{}}}", name))
+    }
+    /// 登记一个绑定参数，返回其占位符，字面量模式下无法转义的值返回错误
+    pub(crate) fn push_value(&mut self, value: &RdbcValue) -> Result<String, RdbcError> {
+        match self.mode {
+            RdbcRenderMode::Named => {
+                let name = self.next_param_name();
+                self.params.insert(name.clone(), value.clone());
+                Ok(format!("#{{{}}}", name))
+            }
+            RdbcRenderMode::Raw => self.dialect.literal(value),
+            RdbcRenderMode::Positional => {
+                self.values.push(self.dialect.bind_value(value));
+                Ok(self.dialect.placeholder(self.values.len()))
+            }
+        }
+    }
+    pub(crate) fn take_values(self) -> Vec<RdbcValue> {
+        self.values
+    }
+    pub(crate) fn take_params(self) -> HashMap<String, RdbcValue> {
+        self.params
+    }
+    /// 设置后续参数命名所用的列
+    pub(crate) fn set_param_hint(&mut self, column: &RdbcColumn) {
+        self.param_hint = match column {
+            RdbcColumn::SimpleColumn(column) => column.column.clone(),
+            RdbcColumn::TableColumn(column) => column.column.clone(),
+            _ => "".to_string(),
+        };
+    }
+    pub(crate) fn set_param_hint_name(&mut self, name: &str) {
+        self.param_hint = name.to_string();
+    }
+    fn next_param_name(&mut self) -> String {
+        let hint = self
+            .param_hint
+            .rsplit('.')
+            .next()
+            .unwrap_or("")
+            .chars()
+            .filter(|c| !matches!(c, '"' | '`' | '[' | ']'))
+            .map(|c| {
+                if c.is_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect::<String>();
+        let hint = if hint.is_empty() {
+            "value".to_string()
+        } else {
+            hint
+        };
+        let index = self.param_index.entry(hint.clone()).or_insert(0);
+        *index += 1;
+        format!("p_{}_{}", hint, index)
+    }
+    pub(crate) fn dialect(&self) -> &'a dyn RdbcSqlDialect {
+        self.dialect
+    }
+
+    /// 校验并按方言引用标识符，schema.table.column 逐段引用
+    pub(crate) fn quote_ident(&self, ident: &str) -> Result<String, RdbcError> {
+        let parts = split_ident(ident)?
+            .into_iter()
+            .map(|part| self.dialect.quote_ident(part))
+            .collect::<Vec<String>>();
+        Ok(parts.join("."))
+    }
+
+    /// RETURNING 子句，未指定返回列时为空串
+    pub(crate) fn render_returning(&self, returning: &[String]) -> Result<String, RdbcError> {
+        if returning.is_empty() {
+            return Ok("".to_string());
+        }
+        if !self.dialect.support_returning() {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "当前数据库不支持 RETURNING".to_string(),
+            ));
+        }
+        Ok(format!(
+            " RETURNING {}",
+            self.render_returning_columns(returning)?
+        ))
+    }
+    pub(crate) fn render_returning_columns(
+        &self,
+        returning: &[String],
+    ) -> Result<String, RdbcError> {
+        let columns = returning
+            .iter()
+            .map(|column| {
+                if column == "*" {
+                    Ok(column.clone())
+                } else {
+                    self.quote_ident(column)
+                }
+            })
+            .collect::<Result<Vec<String>, RdbcError>>()?;
+        Ok(columns.join(", "))
+    }
+
+    pub(crate) fn concat(&self, items: &[&str]) -> String {
+        self.dialect.concat(items)
+    }
+
+    /// 是否支持指定的连接方式
+    pub(crate) fn support_join(&self, join_type: &JoinType) -> bool {
+        match join_type {
+            JoinType::RightJoin => self.dialect.support_right_join(),
+            JoinType::FullJoin => self.dialect.support_full_join(),
+            JoinType::LateralJoin | JoinType::LeftLateralJoin => {
+                self.dialect.support_lateral_join()
+            }
+            _ => true,
+        }
+    }
+
+    pub(crate) fn render_page(
+        &self,
+        sql: String,
+        limit: Option<u64>,
+        offset: Option<u64>,
+    ) -> String {
+        self.dialect.render_page(sql, limit, offset)
+    }
+}
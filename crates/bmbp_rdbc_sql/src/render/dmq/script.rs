@@ -0,0 +1,100 @@
+use crate::{RdbcPostgresDialect, RdbcSqlDialect, RdbcSqlRender};
+use bmbp_rdbc_type::{RdbcErrKind, RdbcError, RdbcValue};
+use std::collections::HashMap;
+
+/// 命名参数脚本，将 #{name} 按方言转换为位置参数
+///
+/// 只替换参数占位符，脚本须由同一方言的渲染器生成（如拼接、分页等语法不会改写）
+pub struct RdbcScriptRender {
+    script: String,
+    params: HashMap<String, RdbcValue>,
+    dialect: Box<dyn RdbcSqlDialect>,
+}
+
+impl RdbcScriptRender {
+    pub fn new(script: String, params: HashMap<String, RdbcValue>) -> Self {
+        Self::new_with_dialect(script, params, RdbcPostgresDialect)
+    }
+    pub fn new_with_dialect<D>(
+        script: String,
+        params: HashMap<String, RdbcValue>,
+        dialect: D,
+    ) -> Self
+    where
+        D: RdbcSqlDialect + 'static,
+    {
+        RdbcScriptRender {
+            script,
+            params,
+            dialect: Box::new(dialect),
+        }
+    }
+}
+
+impl RdbcScriptRender {
+    /// 按出现顺序替换参数，字符串字面量中的 #{} 不做替换
+    fn replace_params<F>(&self, mut replace: F) -> Result<String, RdbcError>
+    where
+        F: FnMut(&RdbcValue) -> Result<String, RdbcError>,
+    {
+        let mut sql = String::with_capacity(self.script.len());
+        let mut in_quote = false;
+        let mut chars = self.script.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == '\'' {
+                in_quote = !in_quote;
+            }
+            if c != '#' || in_quote || chars.peek() != Some(&'{') {
+                sql.push(c);
+                continue;
+            }
+            chars.next();
+            let mut name = String::new();
+            let mut closed = false;
+            for c in chars.by_ref() {
+                if c == '}' {
+                    closed = true;
+                    break;
+                }
+                name.push(c);
+            }
+            if !closed {
+                return Err(RdbcError::new(
+                    RdbcErrKind::SQL,
+                    format!("脚本参数#{{{}缺少结束符", name),
+                ));
+            }
+            let name = name.trim();
+            match self.params.get(name) {
+                Some(value) => sql.push_str(&replace(value)?),
+                None => {
+                    return Err(RdbcError::new(
+                        RdbcErrKind::SQL,
+                        format!("脚本参数{}未赋值", name),
+                    ))
+                }
+            }
+        }
+        Ok(sql)
+    }
+}
+
+impl RdbcSqlRender for RdbcScriptRender {
+    fn render_script(&self) -> Result<(String, HashMap<String, RdbcValue>), RdbcError> {
+        Ok((self.script.clone(), self.params.clone()))
+    }
+
+    fn render_sql(&self) -> Result<(String, Vec<RdbcValue>), RdbcError> {
+        let mut values = vec![];
+        let sql = self.replace_params(|value| {
+            values.push(self.dialect.bind_value(value));
+            Ok(self.dialect.placeholder(values.len()))
+        })?;
+        Ok((sql, values))
+    }
+
+    /// 参数按方言转义为字面量
+    fn render_raw(&self) -> Result<String, RdbcError> {
+        self.replace_params(|value| self.dialect.literal(value))
+    }
+}
@@ -0,0 +1,135 @@
+use crate::render::dmq::render::RdbcRenderContext;
+use crate::{JoinType, RdbcJoinTable, RdbcSimpleTable, RdbcTable};
+use bmbp_rdbc_type::{RdbcErrKind, RdbcError};
+
+impl RdbcRenderContext<'_> {
+    pub(crate) fn render_table(&mut self, table: &RdbcTable) -> Result<String, RdbcError> {
+        let (table_sql, alias) = match table {
+            RdbcTable::SimpleTable(table) => (self.render_simple_table_name(table)?, &table.alias),
+            RdbcTable::QueryTable(table) => (
+                format!("({})", self.render_query(&table.query)?),
+                &table.alias,
+            ),
+            RdbcTable::RawTable(table) => (table.table.clone(), &table.alias),
+        };
+        if alias.is_empty() {
+            Ok(table_sql)
+        } else {
+            Ok(format!("{} {}", table_sql, self.quote_ident(alias)?))
+        }
+    }
+
+    pub(crate) fn render_simple_table_name(
+        &mut self,
+        table: &RdbcSimpleTable,
+    ) -> Result<String, RdbcError> {
+        if table.schema.is_empty() {
+            self.quote_ident(&table.table)
+        } else {
+            Ok(format!(
+                "{}.{}",
+                self.quote_ident(&table.schema)?,
+                self.quote_ident(&table.table)?
+            ))
+        }
+    }
+
+    /// 列引用时使用的表限定名，有别名时使用别名
+    pub(crate) fn render_table_qualifier(
+        &mut self,
+        table: &RdbcTable,
+    ) -> Result<String, RdbcError> {
+        match table {
+            RdbcTable::SimpleTable(table) => {
+                if table.alias.is_empty() {
+                    self.render_simple_table_name(table)
+                } else {
+                    self.quote_ident(&table.alias)
+                }
+            }
+            RdbcTable::QueryTable(table) => self.quote_ident(&table.alias),
+            RdbcTable::RawTable(table) => {
+                if table.alias.is_empty() {
+                    Ok(table.table.clone())
+                } else {
+                    self.quote_ident(&table.alias)
+                }
+            }
+        }
+    }
+
+    pub(crate) fn render_join_table(
+        &mut self,
+        join_table: &RdbcJoinTable,
+    ) -> Result<String, RdbcError> {
+        let has_filter = join_table
+            .filter
+            .as_ref()
+            .is_some_and(|filter| !filter.conditions.is_empty());
+        let join_sql = match join_table.join_type {
+            JoinType::InnerJoin => "INNER JOIN",
+            JoinType::LeftJoin => "LEFT JOIN",
+            JoinType::RightJoin => "RIGHT JOIN",
+            JoinType::FullJoin => "FULL JOIN",
+            JoinType::CrossJoin => "CROSS JOIN",
+            JoinType::NaturalJoin => "NATURAL JOIN",
+            JoinType::NaturalLeftJoin => "NATURAL LEFT JOIN",
+            JoinType::LateralJoin if has_filter => "INNER JOIN LATERAL",
+            JoinType::LateralJoin => "CROSS JOIN LATERAL",
+            JoinType::LeftLateralJoin => "LEFT JOIN LATERAL",
+        };
+        if !self.support_join(&join_table.join_type) {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                format!("当前数据库不支持{}", join_sql),
+            ));
+        }
+        let without_condition = matches!(
+            join_table.join_type,
+            JoinType::CrossJoin | JoinType::NaturalJoin | JoinType::NaturalLeftJoin
+        );
+        if without_condition && (has_filter || !join_table.using.is_empty()) {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                format!("{}不能指定连接条件", join_sql),
+            ));
+        }
+        if has_filter && !join_table.using.is_empty() {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "连接条件 ON 与 USING 不能同时使用".to_string(),
+            ));
+        }
+        let table_sql = self.render_table(&join_table.table)?;
+        if !join_table.using.is_empty() {
+            let using = join_table
+                .using
+                .iter()
+                .map(|column| self.quote_ident(column))
+                .collect::<Result<Vec<String>, RdbcError>>()?;
+            return Ok(format!(
+                "{} {} USING ({})",
+                join_sql,
+                table_sql,
+                using.join(", ")
+            ));
+        }
+        let filter_sql = match join_table.filter.as_ref() {
+            Some(filter) => self.render_filter(filter)?,
+            None => "".to_string(),
+        };
+        if !filter_sql.is_empty() {
+            return Ok(format!("{} {} ON {}", join_sql, table_sql, filter_sql));
+        }
+        match join_table.join_type {
+            JoinType::LeftLateralJoin => Ok(format!("{} {} ON 1 = 1", join_sql, table_sql)),
+            JoinType::InnerJoin | JoinType::LeftJoin | JoinType::RightJoin | JoinType::FullJoin => {
+                Err(RdbcError::new(
+                    RdbcErrKind::SQL,
+                    format!("{}连接缺少连接条件", join_sql),
+                ))
+            }
+            _ => Ok(format!("{} {}", join_sql, table_sql)),
+        }
+    }
+}
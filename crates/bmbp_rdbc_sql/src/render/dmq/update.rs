@@ -1,20 +1,230 @@
+use crate::render::dmq::render::RdbcRenderContext;
+use crate::{
+    JoinType, RdbcColumn, RdbcPostgresDialect, RdbcReturningSql, RdbcSimpleColumn, RdbcSqlDialect,
+    RdbcSqlRender, RdbcTable, RdbcUpdate, RdbcUpdateJoinStyle,
+};
+use bmbp_rdbc_type::{RdbcErrKind, RdbcError, RdbcValue};
 use std::collections::HashMap;
-use bmbp_rdbc_type::RdbcValue;
-use crate::{RdbcSqlRender, RdbcUpdate};
 
 pub struct RdbcUpdateRender {
     update: RdbcUpdate,
+    dialect: Box<dyn RdbcSqlDialect>,
 }
+
+impl RdbcUpdateRender {
+    pub fn new(update: RdbcUpdate) -> Self {
+        Self::new_with_dialect(update, RdbcPostgresDialect)
+    }
+    pub fn new_with_dialect<D>(update: RdbcUpdate, dialect: D) -> Self
+    where
+        D: RdbcSqlDialect + 'static,
+    {
+        RdbcUpdateRender {
+            update,
+            dialect: Box::new(dialect),
+        }
+    }
+}
+
+impl RdbcUpdateRender {
+    /// 渲染带返回行的更新，更新后的行无法可靠回查，仅支持原生 RETURNING
+    pub fn render_returning_sql(&self) -> Result<RdbcReturningSql, RdbcError> {
+        if self.update.returning.is_empty() {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "更新语句未指定返回列".to_string(),
+            ));
+        }
+        let (sql, values) = self.render_sql()?;
+        Ok(RdbcReturningSql::Returning(sql, values))
+    }
+}
+
 impl RdbcSqlRender for RdbcUpdateRender {
-    fn render_script(&self) -> (String, HashMap<String, RdbcValue>) {
-        todo!()
+    fn render_script(&self) -> Result<(String, HashMap<String, RdbcValue>), RdbcError> {
+        let mut context = RdbcRenderContext::new_named(self.dialect.as_ref());
+        let script = context.render_update(&self.update)?;
+        Ok((script, context.take_params()))
+    }
+
+    fn render_sql(&self) -> Result<(String, Vec<RdbcValue>), RdbcError> {
+        let mut context = RdbcRenderContext::new(self.dialect.as_ref());
+        let sql = context.render_update(&self.update)?;
+        Ok((sql, context.take_values()))
     }
 
-    fn render_sql(&self) -> (String, Vec<RdbcValue>) {
-        todo!()
+    fn render_raw(&self) -> Result<String, RdbcError> {
+        RdbcRenderContext::new_raw(self.dialect.as_ref()).render_update(&self.update)
     }
+}
+
+impl RdbcRenderContext<'_> {
+    pub(crate) fn render_update(&mut self, update: &RdbcUpdate) -> Result<String, RdbcError> {
+        if update.column_value.is_empty() {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "更新语句未指定更新列".to_string(),
+            ));
+        }
+        if update.offset.is_some() {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "更新语句不支持 OFFSET".to_string(),
+            ));
+        }
+        let has_limit = !update.order_by.is_empty() || update.limit.is_some();
+        if has_limit && !update.join_table.is_empty() {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "关联更新不支持 ORDER BY、LIMIT".to_string(),
+            ));
+        }
+        if has_limit && !self.dialect().support_update_limit() {
+            return Err(RdbcError::new(
+                RdbcErrKind::SQL,
+                "当前数据库更新语句不支持 ORDER BY、LIMIT".to_string(),
+            ));
+        }
+        let table = match update.table.first() {
+            Some(table) => self.render_table(table)?,
+            None => {
+                return Err(RdbcError::new(
+                    RdbcErrKind::SQL,
+                    "更新语句未指定表".to_string(),
+                ))
+            }
+        };
+
+        let mut sql = format!("UPDATE {}", table);
+        let mut conditions = vec![];
+        if update.join_table.is_empty() {
+            sql.push_str(&format!(" SET {}", self.render_update_set(update)?));
+        } else {
+            match self.dialect().update_join_style() {
+                RdbcUpdateJoinStyle::Join => {
+                    for join_table in update.join_table.iter() {
+                        sql.push_str(&format!(" {}", self.render_join_table(join_table)?));
+                    }
+                    sql.push_str(&format!(" SET {}", self.render_update_set(update)?));
+                }
+                RdbcUpdateJoinStyle::From => {
+                    sql.push_str(&format!(" SET {}", self.render_update_set(update)?));
+                    // 首个关联表作为 FROM 项，其连接条件并入 WHERE
+                    let first = &update.join_table[0];
+                    if !matches!(first.join_type, JoinType::InnerJoin) {
+                        return Err(RdbcError::new(
+                            RdbcErrKind::SQL,
+                            "关联更新的首个关联表必须为内连接".to_string(),
+                        ));
+                    }
+                    sql.push_str(&format!(" FROM {}", self.render_table(&first.table)?));
+                    for join_table in update.join_table.iter().skip(1) {
+                        sql.push_str(&format!(" {}", self.render_join_table(join_table)?));
+                    }
+                    if let Some(filter) = first.filter.as_ref() {
+                        conditions.push(self.render_and_operand(filter)?);
+                    }
+                }
+                RdbcUpdateJoinStyle::Unsupported => {
+                    return Err(RdbcError::new(
+                        RdbcErrKind::SQL,
+                        "当前数据库不支持关联更新".to_string(),
+                    ))
+                }
+            }
+        }
+        if let Some(filter) = update.where_.as_ref() {
+            conditions.push(self.render_and_operand(filter)?);
+        }
+        let conditions = conditions
+            .into_iter()
+            .filter(|condition| !condition.is_empty())
+            .collect::<Vec<String>>();
+        if !conditions.is_empty() {
+            sql.push_str(&format!(" WHERE {}", conditions.join(" AND ")));
+        }
+
+        if !update.order_by.is_empty() {
+            let mut order_by = vec![];
+            for column in update.order_by.iter() {
+                order_by.push(self.render_order_column(column)?);
+            }
+            sql.push_str(&format!(" ORDER BY {}", order_by.join(", ")));
+        }
+        if let Some(limit) = update.limit {
+            sql.push_str(&format!(" LIMIT {}", limit));
+        }
+        sql.push_str(&self.render_returning(&update.returning)?);
+        Ok(sql)
+    }
+
+    fn render_update_set(&mut self, update: &RdbcUpdate) -> Result<String, RdbcError> {
+        let join_style = if update.join_table.is_empty() {
+            None
+        } else {
+            Some(self.dialect().update_join_style())
+        };
+        let target = update.table.first().and_then(update_target_name);
+        let mut sets = vec![];
+        for column_value in update.column_value.iter() {
+            let column = match (join_style, target.as_ref()) {
+                (Some(join_style), Some(target)) => {
+                    self.render_join_update_column(&column_value.column, target, join_style)?
+                }
+                _ => self.render_column(&column_value.column)?,
+            };
+            self.set_param_hint(&column_value.column);
+            let value = self.render_dml_value(&column_value.value)?;
+            sets.push(format!("{} = {}", column, value));
+        }
+        Ok(sets.join(", "))
+    }
+    /// 关联更新的 SET 列：JOIN 形式补全被更新表限定，避免同名列歧义；
+    /// FROM 形式不允许限定，去掉被更新表的限定，其他表的列返回错误
+    fn render_join_update_column(
+        &mut self,
+        column: &RdbcColumn,
+        target: &str,
+        join_style: RdbcUpdateJoinStyle,
+    ) -> Result<String, RdbcError> {
+        let (qualifier, name) = match column {
+            RdbcColumn::SimpleColumn(column) => match column.column.rsplit_once('.') {
+                Some((qualifier, name)) => (Some(qualifier.to_string()), name.to_string()),
+                None => (None, column.column.clone()),
+            },
+            RdbcColumn::TableColumn(column) => {
+                (update_target_name(&column.table), column.column.clone())
+            }
+            _ => return self.render_column(column),
+        };
+        let column = match (join_style, qualifier) {
+            (RdbcUpdateJoinStyle::Join, None) => format!("{}.{}", target, name),
+            (RdbcUpdateJoinStyle::From, Some(qualifier)) if qualifier != target => {
+                return Err(RdbcError::new(
+                    RdbcErrKind::SQL,
+                    format!(
+                        "关联更新只能更新{}的列，不能更新{}.{}",
+                        target, qualifier, name
+                    ),
+                ))
+            }
+            (RdbcUpdateJoinStyle::From, _) => name,
+            _ => return self.render_column(column),
+        };
+        self.render_column(&RdbcColumn::SimpleColumn(RdbcSimpleColumn { column }))
+    }
+}
 
-    fn render_raw(&self) -> String {
-        todo!()
+/// 被更新表在语句中的引用名，有别名时使用别名
+fn update_target_name(table: &RdbcTable) -> Option<String> {
+    match table {
+        RdbcTable::SimpleTable(table) if !table.alias.is_empty() => Some(table.alias.clone()),
+        RdbcTable::SimpleTable(table) if !table.schema.is_empty() => {
+            Some(format!("{}.{}", table.schema, table.table))
+        }
+        RdbcTable::SimpleTable(table) => Some(table.table.clone()),
+        RdbcTable::QueryTable(table) if !table.alias.is_empty() => Some(table.alias.clone()),
+        RdbcTable::RawTable(table) if !table.alias.is_empty() => Some(table.alias.clone()),
+        _ => None,
     }
 }
@@ -0,0 +1,96 @@
+use bmbp_rdbc_sql::case::RdbcCaseBuilder;
+use bmbp_rdbc_sql::dql::RdbcQueryBuilder;
+use bmbp_rdbc_sql::filter::RdbcWhereFilterBuilder;
+use bmbp_rdbc_sql::func::RdbcFuncBuilder;
+use bmbp_rdbc_sql::group::RdbcGroupBuilder;
+use bmbp_rdbc_sql::order::RdbcOrderBuilder;
+use bmbp_rdbc_sql::select::RdbcSelectBuilder;
+use bmbp_rdbc_sql::table::RdbcTableBuilder;
+use bmbp_rdbc_sql::{RdbcOrderType, RdbcQueryRender, RdbcSqlRender};
+use bmbp_rdbc_type::RdbcValue;
+
+#[test]
+pub fn test_render_case_searched() {
+    let mut case_builder = RdbcCaseBuilder::new();
+    case_builder.when_v("启用").eq_v("data_status", "0");
+    case_builder
+        .when_v("停用")
+        .eq_v("data_status", "1")
+        .eq_v("data_flag", "0");
+    case_builder.else_v("未知");
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("dict_code")
+        .select_case_as(case_builder.build(), "status_label")
+        .table("bmbp_config_dict");
+    let (sql, values) = RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT dict_code, CASE WHEN data_status = $1 THEN $2 \
+         WHEN data_status = $3 AND data_flag = $4 THEN $5 ELSE $6 END AS status_label \
+         FROM bmbp_config_dict"
+    );
+    assert_eq!(values.len(), 6);
+    assert!(matches!(&values[1], RdbcValue::Varchar(v) if v == "启用"));
+    assert!(matches!(&values[5], RdbcValue::Varchar(v) if v == "未知"));
+}
+
+#[test]
+pub fn test_render_case_simple_order_group() {
+    let mut case_builder = RdbcCaseBuilder::new_with_column("dict_level");
+    case_builder
+        .when_eq_v("high", 1)
+        .when_eq_v("middle", 2)
+        .else_v(3);
+    let priority = case_builder.build();
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select_case_as(priority.clone(), "priority")
+        .select_func_as(RdbcFuncBuilder::count_all(), "total")
+        .table("bmbp_config_dict")
+        .group_by_case(priority.clone())
+        .order_case(priority, RdbcOrderType::Asc);
+    let sql = RdbcQueryRender::new(query_builder.build())
+        .render_raw()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT CASE dict_level WHEN 'high' THEN 1 WHEN 'middle' THEN 2 ELSE 3 END AS priority, \
+         COUNT(*) AS total FROM bmbp_config_dict \
+         GROUP BY CASE dict_level WHEN 'high' THEN 1 WHEN 'middle' THEN 2 ELSE 3 END \
+         ORDER BY CASE dict_level WHEN 'high' THEN 1 WHEN 'middle' THEN 2 ELSE 3 END ASC"
+    );
+}
+
+#[test]
+pub fn test_render_case_invalid() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select_case(RdbcCaseBuilder::new().build())
+        .table("bmbp_config_dict");
+    assert!(RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .is_err());
+
+    let mut case_builder = RdbcCaseBuilder::new();
+    case_builder.when_eq_v("high", 1);
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select_case(case_builder.build())
+        .table("bmbp_config_dict");
+    assert!(RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .is_err());
+
+    let mut case_builder = RdbcCaseBuilder::new();
+    case_builder.when_v(1);
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select_case(case_builder.build())
+        .table("bmbp_config_dict");
+    assert!(RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .is_err());
+}
@@ -0,0 +1,152 @@
+use bmbp_rdbc_sql::dql::RdbcQueryBuilder;
+use bmbp_rdbc_sql::filter::RdbcWhereFilterBuilder;
+use bmbp_rdbc_sql::limit::RdbcLimitBuilder;
+use bmbp_rdbc_sql::order::RdbcOrderBuilder;
+use bmbp_rdbc_sql::select::RdbcSelectBuilder;
+use bmbp_rdbc_sql::table::RdbcTableBuilder;
+use bmbp_rdbc_sql::union::{RdbcCompoundBuilder, RdbcUnionAllBuilder, RdbcUnionBuilder};
+use bmbp_rdbc_sql::{
+    RdbcOracleDialect, RdbcQuery, RdbcQueryRender, RdbcSqlRender, RdbcSqliteDialect,
+};
+
+fn dict_query(table: &str, dict_type: &str) -> RdbcQuery {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("dict_code")
+        .table(table)
+        .eq_v("dict_type", dict_type);
+    query_builder.build()
+}
+
+#[test]
+pub fn test_render_compound_order() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("dict_code")
+        .table("bmbp_config_dict")
+        .eq_v("dict_type", "A");
+    query_builder
+        .union_all(dict_query("bmbp_config_dict_his", "B"))
+        .except(dict_query("bmbp_config_dict_del", "C"))
+        .union(dict_query("bmbp_config_dict_ext", "D"))
+        .intersect(dict_query("bmbp_config_dict_all", "E"))
+        .compound_order_asc("dict_code")
+        .compound_limit(10)
+        .compound_offset(20);
+    let (sql, values) = RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "(SELECT dict_code FROM bmbp_config_dict WHERE dict_type = $1) \
+         UNION ALL (SELECT dict_code FROM bmbp_config_dict_his WHERE dict_type = $2) \
+         EXCEPT (SELECT dict_code FROM bmbp_config_dict_del WHERE dict_type = $3) \
+         UNION (SELECT dict_code FROM bmbp_config_dict_ext WHERE dict_type = $4) \
+         INTERSECT (SELECT dict_code FROM bmbp_config_dict_all WHERE dict_type = $5) \
+         ORDER BY dict_code ASC LIMIT 10 OFFSET 20"
+    );
+    assert_eq!(values.len(), 5);
+}
+
+#[test]
+pub fn test_render_compound_nest() {
+    // (A UNION B) INTERSECT C，成员自身的排序分页留在括号内
+    let mut member_builder = RdbcQueryBuilder::new();
+    member_builder
+        .select("dict_code")
+        .table("bmbp_config_dict")
+        .eq_v("dict_type", "A");
+    member_builder.union(dict_query("bmbp_config_dict_his", "B"));
+    let mut latest_builder = RdbcQueryBuilder::new();
+    latest_builder
+        .select("dict_code")
+        .table("bmbp_config_dict_ext")
+        .order_desc("update_time");
+    latest_builder.limit(5);
+
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("dict_code")
+        .table("bmbp_config_dict_all");
+    query_builder
+        .intersect(member_builder.build())
+        .except_all(latest_builder.build());
+    let query = query_builder.build();
+    let sql = RdbcQueryRender::new(query.clone()).render_raw().unwrap();
+    assert_eq!(
+        sql,
+        "(SELECT dict_code FROM bmbp_config_dict_all) \
+         INTERSECT ((SELECT dict_code FROM bmbp_config_dict WHERE dict_type = 'A') \
+         UNION (SELECT dict_code FROM bmbp_config_dict_his WHERE dict_type = 'B')) \
+         EXCEPT ALL (SELECT dict_code FROM bmbp_config_dict_ext ORDER BY update_time DESC LIMIT 5)"
+    );
+
+    // SQLite 不允许括号，需嵌套的成员使用子查询包装
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("dict_code")
+        .table("bmbp_config_dict_all");
+    query_builder
+        .intersect(member_builder.build())
+        .except(latest_builder.build())
+        .union(dict_query("bmbp_config_dict_ext", "D"))
+        .compound_order_desc("dict_code")
+        .compound_limit(3);
+    let sql =
+        RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcSqliteDialect::default())
+            .render_raw()
+            .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT dict_code FROM bmbp_config_dict_all \
+         INTERSECT SELECT * FROM (SELECT dict_code FROM bmbp_config_dict WHERE dict_type = 'A' \
+         UNION SELECT dict_code FROM bmbp_config_dict_his WHERE dict_type = 'B') \
+         EXCEPT SELECT * FROM (SELECT dict_code FROM bmbp_config_dict_ext \
+         ORDER BY update_time DESC LIMIT 5) \
+         UNION SELECT dict_code FROM bmbp_config_dict_ext WHERE dict_type = 'D' \
+         ORDER BY dict_code DESC LIMIT 3"
+    );
+    assert!(
+        RdbcQueryRender::new_with_dialect(query, RdbcSqliteDialect::default())
+            .render_raw()
+            .is_err()
+    );
+}
+
+#[test]
+pub fn test_render_compound_oracle() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder.select("dict_code").table("bmbp_config_dict");
+    query_builder.except(dict_query("bmbp_config_dict_his", "B"));
+    let sql = RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcOracleDialect::new(19))
+        .render_raw()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "(SELECT \"dict_code\" FROM \"bmbp_config_dict\") MINUS \
+         (SELECT \"dict_code\" FROM \"bmbp_config_dict_his\" WHERE \"dict_type\" = 'B')"
+    );
+    let sql = RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcOracleDialect::new(21))
+        .render_raw()
+        .unwrap();
+    assert!(sql.contains(") EXCEPT ("));
+
+    query_builder.intersect_all(dict_query("bmbp_config_dict_ext", "C"));
+    assert!(
+        RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcOracleDialect::new(19))
+            .render_raw()
+            .is_err()
+    );
+}
+
+#[test]
+pub fn test_render_compound_without_member() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("dict_code")
+        .table("bmbp_config_dict")
+        .compound_limit(10);
+    assert!(RdbcQueryRender::new(query_builder.build())
+        .render_raw()
+        .is_err());
+}
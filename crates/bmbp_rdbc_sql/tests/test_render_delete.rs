@@ -0,0 +1,95 @@
+use bmbp_rdbc_sql::dml::RdbcDeleteBuilder;
+use bmbp_rdbc_sql::filter::RdbcWhereFilterBuilder;
+use bmbp_rdbc_sql::limit::RdbcLimitBuilder;
+use bmbp_rdbc_sql::table::{RdbcJoinTableBuilder, RdbcTableBuilder};
+use bmbp_rdbc_sql::{
+    RdbcDeleteRender, RdbcMysqlDialect, RdbcPostgresDialect, RdbcSqlRender, RdbcSqliteDialect,
+};
+
+#[test]
+pub fn test_render_delete() {
+    let mut delete_builder = RdbcDeleteBuilder::new();
+    delete_builder.table("bmbp_config_dict");
+    delete_builder.eq_v("dict_code", "sex");
+    let (sql, values) = RdbcDeleteRender::new(delete_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(sql, "DELETE FROM bmbp_config_dict WHERE dict_code = $1");
+    assert_eq!(values.len(), 1);
+
+    let (sql, _) = RdbcDeleteRender::new_with_dialect(
+        delete_builder.build(),
+        RdbcSqliteDialect::new(3_039_000),
+    )
+    .render_sql()
+    .unwrap();
+    assert_eq!(sql, "DELETE FROM bmbp_config_dict WHERE dict_code = ?1");
+}
+
+#[test]
+pub fn test_render_delete_all() {
+    let mut delete_builder = RdbcDeleteBuilder::new();
+    delete_builder.table("bmbp_config_dict");
+    assert!(RdbcDeleteRender::new(delete_builder.build())
+        .render_sql()
+        .is_err());
+
+    delete_builder.allow_delete_all();
+    let (sql, _) = RdbcDeleteRender::new(delete_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(sql, "DELETE FROM bmbp_config_dict");
+}
+
+#[test]
+pub fn test_render_join_delete() {
+    let mut delete_builder = RdbcDeleteBuilder::new();
+    delete_builder.table_as("bmbp_config_dict", "d");
+    delete_builder
+        .join_table_as("bmbp_config_dict", "p")
+        .eq_raw("d.dict_parent_code", "p.dict_code");
+    delete_builder.eq_v("p.data_status", "N");
+    let delete = delete_builder.build();
+
+    let (sql, values) = RdbcDeleteRender::new_with_dialect(delete.clone(), RdbcPostgresDialect)
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "DELETE FROM bmbp_config_dict d USING bmbp_config_dict p \
+         WHERE d.dict_parent_code = p.dict_code AND p.data_status = $1"
+    );
+    assert_eq!(values.len(), 1);
+
+    let (sql, _) = RdbcDeleteRender::new_with_dialect(delete.clone(), RdbcMysqlDialect::default())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "DELETE `d` FROM `bmbp_config_dict` `d` INNER JOIN `bmbp_config_dict` `p` \
+         ON `d`.`dict_parent_code` = p.dict_code WHERE `p`.`data_status` = ?"
+    );
+
+    assert!(
+        RdbcDeleteRender::new_with_dialect(delete, RdbcSqliteDialect::default())
+            .render_sql()
+            .is_err()
+    );
+}
+
+#[test]
+pub fn test_render_delete_limit() {
+    let mut delete_builder = RdbcDeleteBuilder::new();
+    delete_builder.table("bmbp_config_dict");
+    delete_builder.eq_v("data_status", "N");
+    delete_builder.limit(100);
+    let delete = delete_builder.build();
+    let (sql, _) = RdbcDeleteRender::new_with_dialect(delete.clone(), RdbcMysqlDialect::default())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "DELETE FROM `bmbp_config_dict` WHERE `data_status` = ? LIMIT 100"
+    );
+    assert!(RdbcDeleteRender::new(delete).render_sql().is_err());
+}
@@ -0,0 +1,116 @@
+use bmbp_rdbc_sql::distinct::RdbcDistinctBuilder;
+use bmbp_rdbc_sql::dql::RdbcQueryBuilder;
+use bmbp_rdbc_sql::filter::RdbcWhereFilterBuilder;
+use bmbp_rdbc_sql::limit::RdbcLimitBuilder;
+use bmbp_rdbc_sql::order::RdbcOrderBuilder;
+use bmbp_rdbc_sql::select::RdbcSelectBuilder;
+use bmbp_rdbc_sql::table::RdbcTableBuilder;
+use bmbp_rdbc_sql::{
+    RdbcMysqlDialect, RdbcOracleDialect, RdbcQueryRender, RdbcSqlRender, RdbcSqliteDialect,
+};
+
+#[test]
+pub fn test_render_distinct() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .distinct()
+        .select("dict_type")
+        .table("bmbp_config_dict")
+        .eq_v("data_status", "0");
+    let (sql, values) = RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT DISTINCT dict_type FROM bmbp_config_dict WHERE data_status = $1"
+    );
+    assert_eq!(values.len(), 1);
+}
+
+fn latest_dict_query() -> RdbcQueryBuilder {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .distinct_on("d.dict_type")
+        .select("d.dict_type")
+        .select("d.dict_code")
+        .select_as("d.dict_name", "name")
+        .table_as("bmbp_config_dict", "d")
+        .eq_v("d.data_status", "0")
+        .order_asc("d.dict_type")
+        .order_desc("d.update_time");
+    query_builder.limit(10);
+    query_builder
+}
+
+#[test]
+pub fn test_render_distinct_on_pg() {
+    let (sql, values) = RdbcQueryRender::new(latest_dict_query().build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT DISTINCT ON (d.dict_type) d.dict_type, d.dict_code, d.dict_name AS name \
+         FROM bmbp_config_dict d WHERE d.data_status = $1 \
+         ORDER BY d.dict_type ASC, d.update_time DESC LIMIT 10"
+    );
+    assert_eq!(values.len(), 1);
+}
+
+#[test]
+pub fn test_render_distinct_on_rewrite() {
+    let (sql, values) =
+        RdbcQueryRender::new_with_dialect(latest_dict_query().build(), RdbcMysqlDialect::default())
+            .render_sql()
+            .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT `dict_type`, `dict_code`, `name` FROM (SELECT `d`.`dict_type`, `d`.`dict_code`, \
+         `d`.`dict_name` AS `name`, `d`.`dict_type` AS o1_, `d`.`update_time` AS o2_, \
+         ROW_NUMBER() OVER (PARTITION BY `d`.`dict_type` \
+         ORDER BY `d`.`dict_type` ASC, `d`.`update_time` DESC) AS rn_ \
+         FROM `bmbp_config_dict` `d` WHERE `d`.`data_status` = ?) t_ WHERE rn_ = 1 \
+         ORDER BY o1_ ASC, o2_ DESC LIMIT 10"
+    );
+    assert_eq!(values.len(), 1);
+
+    let sql = RdbcQueryRender::new_with_dialect(
+        latest_dict_query().build(),
+        RdbcOracleDialect::default(),
+    )
+    .render_raw()
+    .unwrap();
+    assert!(sql.starts_with("SELECT \"dict_type\", \"dict_code\", \"name\" FROM (SELECT "));
+    assert!(sql.ends_with(") t_ WHERE rn_ = 1 ORDER BY o1_ ASC, o2_ DESC FETCH FIRST 10 ROWS ONLY"));
+
+    // 窗口函数不可用时无法改写
+    assert!(RdbcQueryRender::new_with_dialect(
+        latest_dict_query().build(),
+        RdbcSqliteDialect::new(3_024_000)
+    )
+    .render_sql()
+    .is_err());
+}
+
+#[test]
+pub fn test_render_distinct_on_rewrite_invalid() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .distinct_on("dict_type")
+        .table("bmbp_config_dict");
+    assert!(
+        RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcMysqlDialect::default())
+            .render_sql()
+            .is_err()
+    );
+
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .distinct_on("dict_type")
+        .select_raw("count(1)")
+        .table("bmbp_config_dict");
+    assert!(
+        RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcMysqlDialect::default())
+            .render_sql()
+            .is_err()
+    );
+}
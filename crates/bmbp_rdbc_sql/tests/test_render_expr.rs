@@ -0,0 +1,134 @@
+use bmbp_rdbc_sql::dml::{RdbcInsertBuilder, RdbcUpdateBuilder};
+use bmbp_rdbc_sql::dql::RdbcQueryBuilder;
+use bmbp_rdbc_sql::expr::RdbcExprBuilder;
+use bmbp_rdbc_sql::filter::RdbcWhereFilterBuilder;
+use bmbp_rdbc_sql::func::RdbcFuncBuilder;
+use bmbp_rdbc_sql::select::RdbcSelectBuilder;
+use bmbp_rdbc_sql::table::RdbcTableBuilder;
+use bmbp_rdbc_sql::{
+    RdbcCompare, RdbcInsertRender, RdbcMysqlDialect, RdbcOracleDialect, RdbcQueryRender,
+    RdbcSqlRender, RdbcUpdateRender,
+};
+
+#[test]
+pub fn test_render_expr_update() {
+    let mut update_builder = RdbcUpdateBuilder::new();
+    update_builder
+        .table("bmbp_config_dict")
+        .set_expr("data_sort", RdbcExprBuilder::add_v("data_sort", 1));
+    update_builder.eq_v("dict_code", "sex");
+    let (sql, values) = RdbcUpdateRender::new(update_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "UPDATE bmbp_config_dict SET data_sort = data_sort + $1 WHERE dict_code = $2"
+    );
+    assert_eq!(values.len(), 2);
+}
+
+#[test]
+pub fn test_render_expr_filter() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("order_id")
+        .select_expr_as(RdbcExprBuilder::mul("price", "qty"), "amount")
+        .table("bmbp_order_item")
+        .rdbc_column_v(RdbcExprBuilder::mul("price", "qty"), RdbcCompare::GT, 100);
+    let (sql, values) = RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT order_id, price * qty AS amount FROM bmbp_order_item WHERE price * qty > $1"
+    );
+    assert_eq!(values.len(), 1);
+}
+
+#[test]
+pub fn test_render_expr_nest() {
+    // (price - discount) * qty，同级运算的右侧需要括号
+    let amount = RdbcExprBuilder::mul(RdbcExprBuilder::sub("price", "discount"), "qty");
+    let diff = RdbcExprBuilder::sub("total", RdbcExprBuilder::sub("paid", "refund"));
+    let ratio = RdbcExprBuilder::div(
+        RdbcExprBuilder::mul("paid", "rate"),
+        RdbcExprBuilder::modulo("total", "base"),
+    );
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select_expr_as(amount, "amount")
+        .select_expr_as(diff, "diff")
+        .select_expr_as(ratio.clone(), "ratio")
+        .select_expr_as(RdbcExprBuilder::neg(RdbcExprBuilder::add("a", "b")), "neg")
+        .select_expr_as(
+            RdbcExprBuilder::cast(RdbcFuncBuilder::sum("amount"), "NUMERIC(18, 2)"),
+            "total",
+        )
+        .table("bmbp_order");
+    let sql = RdbcQueryRender::new(query_builder.build())
+        .render_raw()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT (price - discount) * qty AS amount, total - (paid - refund) AS diff, \
+         paid * rate / (total % base) AS ratio, -(a + b) AS neg, \
+         CAST(SUM(amount) AS NUMERIC(18, 2)) AS total FROM bmbp_order"
+    );
+
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select_expr_as(ratio, "ratio")
+        .table("bmbp_order");
+    let sql =
+        RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcOracleDialect::default())
+            .render_raw()
+            .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT \"paid\" * \"rate\" / (MOD(\"total\", \"base\")) AS \"ratio\" FROM \"bmbp_order\""
+    );
+}
+
+#[test]
+pub fn test_render_expr_concat() {
+    let label = RdbcExprBuilder::concat(
+        RdbcExprBuilder::concat_v("dict_code", "-"),
+        RdbcExprBuilder::cast(RdbcExprBuilder::add_v("data_sort", 1), "VARCHAR(10)"),
+    );
+    let mut insert_builder = RdbcInsertBuilder::new();
+    insert_builder
+        .insert_table("bmbp_config_dict")
+        .insert_col_expr("dict_label", label.clone());
+    let sql = RdbcInsertRender::new(insert_builder.build())
+        .render_raw()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "INSERT INTO bmbp_config_dict (dict_label) \
+         VALUES (dict_code || '-' || CAST(data_sort + 1 AS VARCHAR(10)))"
+    );
+
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select_expr_as(label, "dict_label")
+        .table("bmbp_config_dict");
+    let sql = RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcMysqlDialect::default())
+        .render_raw()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT CONCAT(`dict_code`, '-', CAST(`data_sort` + 1 AS VARCHAR(10))) \
+         AS `dict_label` FROM `bmbp_config_dict`"
+    );
+}
+
+#[test]
+pub fn test_render_expr_invalid_cast() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select_expr(RdbcExprBuilder::cast("amount", "INT); DROP TABLE x;--"))
+        .table("bmbp_order");
+    assert!(RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .is_err());
+}
@@ -0,0 +1,275 @@
+use bmbp_rdbc_sql::dql::RdbcQueryBuilder;
+use bmbp_rdbc_sql::filter::RdbcWhereFilterBuilder;
+use bmbp_rdbc_sql::table::RdbcTableBuilder;
+use bmbp_rdbc_sql::{
+    RdbcColumn, RdbcCompare, RdbcFilterValue, RdbcQuery, RdbcQueryRender, RdbcSimpleColumn,
+    RdbcSqlRender, RdbcWhereCondition, RdbcWhereSimpleCondition,
+};
+use bmbp_rdbc_type::{RdbcError, RdbcValue};
+
+fn simple_column(column: &str) -> RdbcColumn {
+    RdbcColumn::SimpleColumn(RdbcSimpleColumn {
+        column: column.to_string(),
+    })
+}
+
+fn sub_query() -> RdbcQuery {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder.table("bmbp_config_dict");
+    query_builder.eq_v("dict_type", "A");
+    query_builder.build()
+}
+
+fn condition_query(compare: RdbcCompare, value: RdbcFilterValue) -> RdbcQuery {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder.table("t");
+    query_builder
+        .filter_mut()
+        .conditions
+        .push(RdbcWhereCondition::Simple(RdbcWhereSimpleCondition {
+            column: simple_column("c"),
+            compare,
+            value,
+        }));
+    query_builder.build()
+}
+
+/// 渲染单个条件，返回 WHERE 之后的部分及绑定参数
+fn render_condition(
+    compare: RdbcCompare,
+    value: RdbcFilterValue,
+) -> Result<(String, Vec<RdbcValue>), RdbcError> {
+    let (sql, values) = RdbcQueryRender::new(condition_query(compare, value)).render_sql()?;
+    Ok((
+        sql.trim_start_matches("SELECT * FROM t WHERE ").to_string(),
+        values,
+    ))
+}
+
+fn render(compare: RdbcCompare, value: RdbcFilterValue) -> String {
+    render_condition(compare, value).unwrap().0
+}
+
+/// 脚本参数只能在命名参数模式下渲染
+fn render_script(compare: RdbcCompare, name: &str) -> String {
+    let script = RdbcFilterValue::Script(name.to_string());
+    let query = condition_query(compare.clone(), script.clone());
+    assert!(RdbcQueryRender::new(query.clone()).render_sql().is_err());
+    assert!(RdbcQueryRender::new(query.clone()).render_raw().is_err());
+    let (sql, _) = RdbcQueryRender::new(query).render_script().unwrap();
+    sql.trim_start_matches("SELECT * FROM t WHERE ").to_string()
+}
+
+fn value(value: i32) -> RdbcFilterValue {
+    RdbcFilterValue::Value(RdbcValue::Int(value))
+}
+
+fn array(items: Vec<i32>) -> RdbcFilterValue {
+    RdbcFilterValue::Value(RdbcValue::Array(
+        items.into_iter().map(RdbcValue::Int).collect(),
+    ))
+}
+
+#[test]
+pub fn test_render_filter_compare() {
+    let cases = [
+        (RdbcCompare::EQ, "="),
+        (RdbcCompare::NE, "<>"),
+        (RdbcCompare::GT, ">"),
+        (RdbcCompare::GE, ">="),
+        (RdbcCompare::LT, "<"),
+        (RdbcCompare::LE, "<="),
+    ];
+    for (compare, op) in cases {
+        assert_eq!(render(compare.clone(), value(1)), format!("c {} $1", op));
+        assert_eq!(
+            render(compare.clone(), RdbcFilterValue::Column(simple_column("d"))),
+            format!("c {} d", op)
+        );
+        assert_eq!(
+            render(compare.clone(), RdbcFilterValue::Query(sub_query())),
+            format!(
+                "c {} (SELECT * FROM bmbp_config_dict WHERE dict_type = $1)",
+                op
+            )
+        );
+        assert_eq!(
+            render_script(compare.clone(), "code"),
+            format!("c {} #{{code}}", op)
+        );
+        assert_eq!(
+            render(compare.clone(), RdbcFilterValue::Raw("now()".to_string())),
+            format!("c {} now()", op)
+        );
+        assert!(render_condition(compare, RdbcFilterValue::None).is_err());
+    }
+    assert_eq!(
+        render(RdbcCompare::EQ, RdbcFilterValue::Value(RdbcValue::Null)),
+        "c IS NULL"
+    );
+    assert_eq!(
+        render(RdbcCompare::NE, RdbcFilterValue::Value(RdbcValue::Null)),
+        "c IS NOT NULL"
+    );
+}
+
+#[test]
+pub fn test_render_filter_like() {
+    let cases = [
+        (RdbcCompare::Like, "LIKE '%' || $1 || '%'"),
+        (RdbcCompare::LikeLeft, "LIKE '%' || $1"),
+        (RdbcCompare::LikeRight, "LIKE $1 || '%'"),
+        (RdbcCompare::NotLike, "NOT LIKE '%' || $1 || '%'"),
+        (RdbcCompare::NotLikeLeft, "NOT LIKE '%' || $1"),
+        (RdbcCompare::NotLikeRight, "NOT LIKE $1 || '%'"),
+    ];
+    for (compare, expect) in cases {
+        let (sql, values) = render_condition(
+            compare.clone(),
+            RdbcFilterValue::Value(RdbcValue::from("典")),
+        )
+        .unwrap();
+        assert_eq!(sql, format!("c {}", expect));
+        assert!(matches!(&values[0], RdbcValue::Varchar(v) if v == "典"));
+        assert!(render_condition(compare, RdbcFilterValue::None).is_err());
+    }
+    assert_eq!(
+        render(
+            RdbcCompare::LikeRight,
+            RdbcFilterValue::Column(simple_column("d"))
+        ),
+        "c LIKE d || '%'"
+    );
+    assert_eq!(
+        render_script(RdbcCompare::LikeLeft, "name"),
+        "c LIKE '%' || #{name}"
+    );
+    assert_eq!(
+        render(RdbcCompare::Like, RdbcFilterValue::Raw("'x'".to_string())),
+        "c LIKE '%' || 'x' || '%'"
+    );
+}
+
+#[test]
+pub fn test_render_filter_in() {
+    let (sql, values) = render_condition(RdbcCompare::IN, array(vec![1, 2, 3])).unwrap();
+    assert_eq!(sql, "c IN ($1, $2, $3)");
+    assert_eq!(values.len(), 3);
+    assert_eq!(render(RdbcCompare::NotIn, array(vec![1])), "c NOT IN ($1)");
+    let (sql, values) = render_condition(RdbcCompare::IN, array(vec![])).unwrap();
+    assert_eq!(sql, "1 = 0");
+    assert!(values.is_empty());
+    assert_eq!(render(RdbcCompare::NotIn, array(vec![])), "1 = 1");
+    assert_eq!(render(RdbcCompare::IN, value(1)), "c IN ($1)");
+    assert_eq!(
+        render(RdbcCompare::IN, RdbcFilterValue::Query(sub_query())),
+        "c IN (SELECT * FROM bmbp_config_dict WHERE dict_type = $1)"
+    );
+    assert_eq!(
+        render(RdbcCompare::NotIn, RdbcFilterValue::Raw("1, 2".to_string())),
+        "c NOT IN (1, 2)"
+    );
+    assert_eq!(render_script(RdbcCompare::IN, "codes"), "c IN (#{codes})");
+    assert_eq!(
+        render(RdbcCompare::IN, RdbcFilterValue::Column(simple_column("d"))),
+        "c IN (d)"
+    );
+    assert!(render_condition(RdbcCompare::IN, RdbcFilterValue::None).is_err());
+}
+
+#[test]
+pub fn test_render_filter_exists() {
+    assert_eq!(
+        render(RdbcCompare::Exists, RdbcFilterValue::Query(sub_query())),
+        "EXISTS (SELECT * FROM bmbp_config_dict WHERE dict_type = $1)"
+    );
+    assert_eq!(
+        render(
+            RdbcCompare::NotExits,
+            RdbcFilterValue::Raw("SELECT 1".to_string())
+        ),
+        "NOT EXISTS (SELECT 1)"
+    );
+    assert!(render_condition(RdbcCompare::Exists, value(1)).is_err());
+    assert!(render_condition(RdbcCompare::Exists, RdbcFilterValue::None).is_err());
+    assert!(render_condition(
+        RdbcCompare::NotExits,
+        RdbcFilterValue::Script("q".to_string())
+    )
+    .is_err());
+
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder.table("t");
+    query_builder.exists(sub_query()).not_exists(sub_query());
+    let (sql, values) = RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT * FROM t WHERE EXISTS (SELECT * FROM bmbp_config_dict WHERE dict_type = $1) \
+         AND NOT EXISTS (SELECT * FROM bmbp_config_dict WHERE dict_type = $2)"
+    );
+    assert_eq!(values.len(), 2);
+}
+
+#[test]
+pub fn test_render_filter_null() {
+    assert_eq!(
+        render(RdbcCompare::IsNull, RdbcFilterValue::None),
+        "c IS NULL"
+    );
+    assert_eq!(
+        render(RdbcCompare::IsNotNull, RdbcFilterValue::None),
+        "c IS NOT NULL"
+    );
+    let (sql, values) = render_condition(RdbcCompare::IsNull, value(1)).unwrap();
+    assert_eq!(sql, "c IS NULL");
+    assert!(values.is_empty());
+}
+
+#[test]
+pub fn test_render_filter_between() {
+    let (sql, values) = render_condition(RdbcCompare::Between, array(vec![1, 10])).unwrap();
+    assert_eq!(sql, "c BETWEEN $1 AND $2");
+    assert_eq!(values.len(), 2);
+    assert_eq!(
+        render(RdbcCompare::NotBetween, array(vec![1, 10])),
+        "c NOT BETWEEN $1 AND $2"
+    );
+    assert_eq!(
+        render(
+            RdbcCompare::Between,
+            RdbcFilterValue::Raw("1 AND 10".to_string())
+        ),
+        "c BETWEEN 1 AND 10"
+    );
+    assert!(render_condition(RdbcCompare::Between, array(vec![1])).is_err());
+    assert!(render_condition(RdbcCompare::Between, value(1)).is_err());
+    assert!(render_condition(RdbcCompare::Between, RdbcFilterValue::None).is_err());
+    assert!(render_condition(RdbcCompare::Between, RdbcFilterValue::Query(sub_query())).is_err());
+}
+
+#[test]
+pub fn test_builder_filter_compare() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder.table("t");
+    query_builder
+        .lt_v("a", 1)
+        .lt_script("a", "a")
+        .lt_raw("a", "1")
+        .le_v("a", 1)
+        .le_script("a", "a")
+        .le_raw("a", "1")
+        .null("n")
+        .not_null("m")
+        .not_between_v("s", 1, 2i64);
+    let (sql, _) = RdbcQueryRender::new(query_builder.build())
+        .render_script()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT * FROM t WHERE a < #{p_a_1} AND a < #{a} AND a < 1 \
+         AND a <= #{p_a_2} AND a <= #{a} AND a <= 1 AND n IS NULL AND m IS NOT NULL \
+         AND s NOT BETWEEN #{p_s_1} AND #{p_s_2}"
+    );
+}
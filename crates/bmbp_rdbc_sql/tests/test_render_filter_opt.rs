@@ -0,0 +1,118 @@
+use bmbp_rdbc_sql::dql::RdbcQueryBuilder;
+use bmbp_rdbc_sql::filter::RdbcWhereFilterBuilder;
+use bmbp_rdbc_sql::table::RdbcTableBuilder;
+use bmbp_rdbc_sql::{RdbcCompare, RdbcQueryRender, RdbcSqlRender, RdbcValueSkip};
+use bmbp_rdbc_type::RdbcValue;
+
+/// 模拟查询页面提交的可选条件
+#[derive(Default)]
+struct DictSearch {
+    dict_code: Option<String>,
+    dict_name: Option<String>,
+    dict_type: Option<Vec<String>>,
+    start_order: Option<i32>,
+    end_order: Option<i32>,
+}
+
+fn render_search(search: &DictSearch) -> (String, Vec<RdbcValue>) {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder.table("bmbp_config_dict");
+    query_builder
+        .eq_v_opt("dict_code", &search.dict_code)
+        .like_v_opt("dict_name", &search.dict_name)
+        .in_v_opt("dict_type", search.dict_type.clone())
+        .between_v_opt("dict_order", search.start_order, search.end_order);
+    RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .unwrap()
+}
+
+#[test]
+pub fn test_render_filter_opt_all() {
+    let search = DictSearch {
+        dict_code: Some("sex".to_string()),
+        dict_name: Some("性别".to_string()),
+        dict_type: Some(vec!["A".to_string(), "B".to_string()]),
+        start_order: Some(1),
+        end_order: Some(9),
+    };
+    let (sql, values) = render_search(&search);
+    assert_eq!(
+        sql,
+        "SELECT * FROM bmbp_config_dict WHERE dict_code = $1 AND dict_name LIKE '%' || $2 || '%' \
+         AND dict_type IN ($3, $4) AND dict_order BETWEEN $5 AND $6"
+    );
+    assert_eq!(values.len(), 6);
+}
+
+#[test]
+pub fn test_render_filter_opt_skip() {
+    let (sql, values) = render_search(&DictSearch::default());
+    assert_eq!(sql, "SELECT * FROM bmbp_config_dict");
+    assert!(values.is_empty());
+
+    let search = DictSearch {
+        dict_code: Some("".to_string()),
+        dict_name: None,
+        dict_type: Some(vec![]),
+        start_order: Some(1),
+        end_order: None,
+    };
+    let (sql, values) = render_search(&search);
+    assert_eq!(sql, "SELECT * FROM bmbp_config_dict WHERE dict_order >= $1");
+    assert_eq!(values.len(), 1);
+
+    let search = DictSearch {
+        end_order: Some(9),
+        ..Default::default()
+    };
+    let (sql, _) = render_search(&search);
+    assert_eq!(sql, "SELECT * FROM bmbp_config_dict WHERE dict_order <= $1");
+}
+
+#[test]
+pub fn test_render_filter_value_skip() {
+    assert!(RdbcValueSkip::None.is_skip(&RdbcValue::Null));
+    assert!(!RdbcValueSkip::None.is_skip(&RdbcValue::from("")));
+    assert!(RdbcValueSkip::Empty.is_skip(&RdbcValue::from("")));
+    assert!(!RdbcValueSkip::Empty.is_skip(&RdbcValue::Null));
+    assert!(RdbcValueSkip::Both.is_skip(&RdbcValue::Array(vec![])));
+    assert!(!RdbcValueSkip::Both.is_skip(&RdbcValue::from(0)));
+
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .table("bmbp_config_dict")
+        .simple_value_skip("dict_code", RdbcCompare::EQ, "", RdbcValueSkip::None)
+        .simple_value_skip(
+            "dict_name",
+            RdbcCompare::EQ,
+            None::<String>,
+            RdbcValueSkip::Empty,
+        );
+    let (sql, values) = RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT * FROM bmbp_config_dict WHERE dict_code = $1 AND dict_name IS NULL"
+    );
+    assert_eq!(values.len(), 1);
+}
+
+#[test]
+pub fn test_render_filter_if() {
+    let only_enabled = true;
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .table("bmbp_config_dict")
+        .filter_if(only_enabled, |filter| {
+            filter.eq_v("data_status", "1");
+        })
+        .filter_if(!only_enabled, |filter| {
+            filter.eq_v("data_status", "0");
+        });
+    let (sql, _) = RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(sql, "SELECT * FROM bmbp_config_dict WHERE data_status = $1");
+}
@@ -0,0 +1,258 @@
+use bmbp_rdbc_sql::dql::RdbcQueryBuilder;
+use bmbp_rdbc_sql::filter::RdbcWhereFilterBuilder;
+use bmbp_rdbc_sql::func::RdbcFuncBuilder;
+use bmbp_rdbc_sql::group::RdbcGroupBuilder;
+use bmbp_rdbc_sql::having::RdbcHavingBuilder;
+use bmbp_rdbc_sql::order::RdbcOrderBuilder;
+use bmbp_rdbc_sql::select::RdbcSelectBuilder;
+use bmbp_rdbc_sql::table::RdbcTableBuilder;
+use bmbp_rdbc_sql::{
+    RdbcColumn, RdbcCompare, RdbcCountFunc, RdbcDateUnit, RdbcFunc, RdbcMysqlDialect,
+    RdbcOracleDialect, RdbcPostgresDialect, RdbcQueryRender, RdbcSqlDialect, RdbcSqlRender,
+    RdbcSqliteDialect,
+};
+use bmbp_rdbc_type::RdbcValue;
+
+fn render_func<D>(func: RdbcFunc, dialect: D) -> String
+where
+    D: RdbcSqlDialect + 'static,
+{
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder.select_func(func).table("t");
+    let (sql, _) = RdbcQueryRender::new_with_dialect(query_builder.build(), dialect)
+        .render_sql()
+        .unwrap();
+    sql.trim_start_matches("SELECT ")
+        .trim_end_matches(" FROM t")
+        .trim_end_matches(" FROM `t`")
+        .trim_end_matches(" FROM \"t\"")
+        .to_string()
+}
+
+#[test]
+pub fn test_render_func_aggregate() {
+    let pg = || RdbcPostgresDialect;
+    assert_eq!(render_func(RdbcFuncBuilder::count_all(), pg()), "COUNT(*)");
+    assert_eq!(render_func(RdbcFuncBuilder::count("a"), pg()), "COUNT(a)");
+    assert_eq!(
+        render_func(RdbcFuncBuilder::count_distinct("a"), pg()),
+        "COUNT(DISTINCT a)"
+    );
+    assert_eq!(render_func(RdbcFuncBuilder::min("a"), pg()), "MIN(a)");
+    assert_eq!(render_func(RdbcFuncBuilder::max("a"), pg()), "MAX(a)");
+    assert_eq!(render_func(RdbcFuncBuilder::avg("a"), pg()), "AVG(a)");
+    assert_eq!(render_func(RdbcFuncBuilder::sum("a"), pg()), "SUM(a)");
+
+    let string_agg = RdbcFuncBuilder::string_agg("a", ",");
+    assert_eq!(render_func(string_agg.clone(), pg()), "STRING_AGG(a, ',')");
+    assert_eq!(
+        render_func(string_agg.clone(), RdbcMysqlDialect::default()),
+        "GROUP_CONCAT(`a` SEPARATOR ',')"
+    );
+    assert_eq!(
+        render_func(string_agg.clone(), RdbcSqliteDialect::default()),
+        "GROUP_CONCAT(a, ',')"
+    );
+    assert_eq!(
+        render_func(string_agg, RdbcOracleDialect::default()),
+        "LISTAGG(\"a\", ',') WITHIN GROUP (ORDER BY NULL)"
+    );
+}
+
+#[test]
+pub fn test_render_func_count_distinct_columns() {
+    let count = RdbcFunc::COUNT(RdbcCountFunc {
+        column: vec![RdbcColumn::from("a"), RdbcColumn::from("b")],
+        distinct: true,
+    });
+    assert_eq!(
+        render_func(count.clone(), RdbcPostgresDialect),
+        "COUNT(DISTINCT (a, b))"
+    );
+    assert_eq!(
+        render_func(count.clone(), RdbcMysqlDialect::default()),
+        "COUNT(DISTINCT `a`, `b`)"
+    );
+    for dialect in [
+        Box::new(RdbcOracleDialect::default()) as Box<dyn RdbcSqlDialect>,
+        Box::new(RdbcSqliteDialect::default()),
+    ] {
+        let mut query_builder = RdbcQueryBuilder::new();
+        query_builder.select_func(count.clone()).table("t");
+        assert!(
+            RdbcQueryRender::new_with_dialect(query_builder.build(), dialect)
+                .render_sql()
+                .is_err()
+        );
+    }
+}
+
+#[test]
+pub fn test_render_func_string_agg_value() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select_func(RdbcFuncBuilder::string_agg(
+            RdbcFuncBuilder::coalesce_v("a", "-"),
+            ",",
+        ))
+        .table("t")
+        .eq_v("b", 1);
+    let (sql, values) =
+        RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcOracleDialect::default())
+            .render_sql()
+            .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT LISTAGG(COALESCE(\"a\", :1), ',') WITHIN GROUP (ORDER BY NULL) \
+         FROM \"t\" WHERE \"b\" = :2"
+    );
+    assert_eq!(values.len(), 2);
+}
+
+#[test]
+pub fn test_render_func_string() {
+    let pg = || RdbcPostgresDialect;
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select_func(RdbcFuncBuilder::coalesce_v("a", 0))
+        .select_func(RdbcFuncBuilder::nullif("a", "b"))
+        .select_func(RdbcFuncBuilder::replace("a", "-", ""))
+        .table("t");
+    let (sql, values) = RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT COALESCE(a, $1), NULLIF(a, b), REPLACE(a, $2, $3) FROM t"
+    );
+    assert_eq!(values.len(), 3);
+
+    let concat = RdbcFuncBuilder::concat(vec![
+        RdbcColumn::from("a"),
+        RdbcColumn::from(RdbcFuncBuilder::upper("b")),
+    ]);
+    assert_eq!(render_func(concat.clone(), pg()), "(a || UPPER(b))");
+    assert_eq!(
+        render_func(concat, RdbcMysqlDialect::default()),
+        "(CONCAT(`a`, UPPER(`b`)))"
+    );
+    assert_eq!(
+        render_func(RdbcFuncBuilder::lower(RdbcFuncBuilder::trim("a")), pg()),
+        "LOWER(TRIM(a))"
+    );
+    assert_eq!(
+        render_func(RdbcFuncBuilder::substring("a", 2, Some(3)), pg()),
+        "SUBSTR(a, 2, 3)"
+    );
+    assert_eq!(
+        render_func(RdbcFuncBuilder::substring("a", 2, None), pg()),
+        "SUBSTR(a, 2)"
+    );
+    assert_eq!(render_func(RdbcFuncBuilder::length("a"), pg()), "LENGTH(a)");
+    assert_eq!(
+        render_func(RdbcFuncBuilder::length("a"), RdbcMysqlDialect::default()),
+        "CHAR_LENGTH(`a`)"
+    );
+}
+
+#[test]
+pub fn test_render_func_date() {
+    let pg = || RdbcPostgresDialect;
+    assert_eq!(
+        render_func(RdbcFuncBuilder::now(), pg()),
+        "CURRENT_TIMESTAMP"
+    );
+    assert_eq!(
+        render_func(RdbcFuncBuilder::current_date(), pg()),
+        "CURRENT_DATE"
+    );
+
+    let trunc = RdbcFuncBuilder::date_trunc(RdbcDateUnit::Month, "d");
+    assert_eq!(render_func(trunc.clone(), pg()), "DATE_TRUNC('month', d)");
+    assert_eq!(
+        render_func(trunc.clone(), RdbcMysqlDialect::default()),
+        "CAST(DATE_FORMAT(`d`, '%Y-%m-01') AS DATETIME)"
+    );
+    assert_eq!(
+        render_func(trunc.clone(), RdbcSqliteDialect::default()),
+        "STRFTIME('%Y-%m-01 00:00:00', d)"
+    );
+    assert_eq!(
+        render_func(trunc, RdbcOracleDialect::default()),
+        "TRUNC(\"d\", 'MM')"
+    );
+
+    let extract = RdbcFuncBuilder::extract(RdbcDateUnit::Year, "d");
+    assert_eq!(render_func(extract.clone(), pg()), "EXTRACT(YEAR FROM d)");
+    assert_eq!(
+        render_func(extract, RdbcSqliteDialect::default()),
+        "CAST(STRFTIME('%Y', d) AS INTEGER)"
+    );
+
+    let add = RdbcFuncBuilder::date_add("d", -3, RdbcDateUnit::Day);
+    assert_eq!(render_func(add.clone(), pg()), "(d + INTERVAL '-3 day')");
+    assert_eq!(
+        render_func(add.clone(), RdbcMysqlDialect::default()),
+        "DATE_ADD(`d`, INTERVAL -3 DAY)"
+    );
+    assert_eq!(
+        render_func(add.clone(), RdbcSqliteDialect::default()),
+        "DATETIME(d, '-3 days')"
+    );
+    assert_eq!(
+        render_func(add, RdbcOracleDialect::default()),
+        "(\"d\" + NUMTODSINTERVAL(-3, 'DAY'))"
+    );
+    assert_eq!(
+        render_func(
+            RdbcFuncBuilder::date_add("d", 1, RdbcDateUnit::Year),
+            RdbcOracleDialect::default()
+        ),
+        "ADD_MONTHS(\"d\", 12)"
+    );
+}
+
+#[test]
+pub fn test_render_func_math() {
+    let pg = || RdbcPostgresDialect;
+    assert_eq!(
+        render_func(RdbcFuncBuilder::round("a", Some(2)), pg()),
+        "ROUND(a, 2)"
+    );
+    assert_eq!(
+        render_func(RdbcFuncBuilder::round("a", None), pg()),
+        "ROUND(a)"
+    );
+    assert_eq!(render_func(RdbcFuncBuilder::floor("a"), pg()), "FLOOR(a)");
+    assert_eq!(render_func(RdbcFuncBuilder::ceil("a"), pg()), "CEIL(a)");
+    assert_eq!(
+        render_func(RdbcFuncBuilder::abs(RdbcValue::Int(-1)), pg()),
+        "ABS($1)"
+    );
+}
+
+#[test]
+pub fn test_render_func_clauses() {
+    let month = RdbcFuncBuilder::date_trunc(RdbcDateUnit::Month, "create_time");
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select_func_as(month.clone(), "month")
+        .select_func_as(RdbcFuncBuilder::count_all(), "total")
+        .table("bmbp_config_dict");
+    query_builder.rdbc_column_v(RdbcFuncBuilder::upper("dict_type"), RdbcCompare::EQ, "A");
+    query_builder.group_by_rdbc_column(RdbcColumn::from(month));
+    query_builder
+        .having_mut()
+        .rdbc_column_v(RdbcFuncBuilder::count_all(), RdbcCompare::GT, 1);
+    query_builder.order_desc(RdbcFuncBuilder::count_all());
+    let (sql, values) = RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT DATE_TRUNC('month', create_time) AS month, COUNT(*) AS total \
+         FROM bmbp_config_dict WHERE UPPER(dict_type) = $1 \
+         GROUP BY DATE_TRUNC('month', create_time) HAVING COUNT(*) > $2 ORDER BY COUNT(*) DESC"
+    );
+    assert_eq!(values.len(), 2);
+}
@@ -0,0 +1,120 @@
+use bmbp_rdbc_sql::dql::RdbcQueryBuilder;
+use bmbp_rdbc_sql::filter::RdbcWhereFilterBuilder;
+use bmbp_rdbc_sql::func::RdbcFuncBuilder;
+use bmbp_rdbc_sql::group::RdbcGroupBuilder;
+use bmbp_rdbc_sql::order::RdbcOrderBuilder;
+use bmbp_rdbc_sql::select::RdbcSelectBuilder;
+use bmbp_rdbc_sql::table::{RdbcJoinTableBuilder, RdbcTableBuilder};
+use bmbp_rdbc_sql::{RdbcMysqlDialect, RdbcQueryRender, RdbcSqlRender};
+use bmbp_rdbc_type::RdbcColumnIdent;
+
+struct DictCode;
+impl RdbcColumnIdent for DictCode {
+    fn table_name() -> String {
+        "bmbp_config_dict".to_string()
+    }
+    fn table_alias() -> String {
+        "d".to_string()
+    }
+    fn column_name() -> String {
+        "dict_code".to_string()
+    }
+}
+
+struct DictName;
+impl RdbcColumnIdent for DictName {
+    fn table_alias() -> String {
+        "d".to_string()
+    }
+    fn column_name() -> String {
+        "dict_name".to_string()
+    }
+}
+
+struct ItemDictCode;
+impl RdbcColumnIdent for ItemDictCode {
+    fn table_alias() -> String {
+        "i".to_string()
+    }
+    fn column_name() -> String {
+        "dict_code".to_string()
+    }
+}
+
+struct ItemOrder;
+impl RdbcColumnIdent for ItemOrder {
+    fn table_name() -> String {
+        "bmbp_config_dict_item".to_string()
+    }
+    fn column_name() -> String {
+        "item_order".to_string()
+    }
+}
+
+struct DataStatus;
+impl RdbcColumnIdent for DataStatus {
+    fn column_name() -> String {
+        "data_status".to_string()
+    }
+}
+
+/// 仍实现旧的 table_alis 的类型
+struct LegacyDictCode;
+#[allow(deprecated)]
+impl RdbcColumnIdent for LegacyDictCode {
+    fn table_alis() -> String {
+        "d".to_string()
+    }
+    fn column_name() -> String {
+        "dict_code".to_string()
+    }
+}
+
+#[test]
+pub fn test_render_ident_join_query() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select_ident(DictCode)
+        .select_ident_as(DictName, "name")
+        .select_func_as(RdbcFuncBuilder::count_all(), "item_count")
+        .table_as("bmbp_config_dict", "d");
+    query_builder
+        .left_join_table_as("bmbp_config_dict_item", "i")
+        .eq_ident_col(ItemDictCode, DictCode);
+    query_builder
+        .eq_ident_v(DataStatus, "1")
+        .in_ident_v(DictCode, vec!["sex", "nation"])
+        .group_by_ident(DictCode)
+        .group_by_ident(DictName)
+        .order_ident_asc(DictCode)
+        .order_ident_desc(ItemOrder);
+    let (sql, values) = RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT d.dict_code, d.dict_name AS name, COUNT(*) AS item_count \
+         FROM bmbp_config_dict d LEFT JOIN bmbp_config_dict_item i ON i.dict_code = d.dict_code \
+         WHERE data_status = $1 AND d.dict_code IN ($2, $3) \
+         GROUP BY d.dict_code, d.dict_name \
+         ORDER BY d.dict_code ASC, bmbp_config_dict_item.item_order DESC"
+    );
+    assert_eq!(values.len(), 3);
+}
+
+#[test]
+pub fn test_render_ident_quote() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select_ident(LegacyDictCode)
+        .table_as("bmbp_config_dict", "d")
+        .eq_ident_v(DictName, "性别");
+    let (sql, _) =
+        RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcMysqlDialect::default())
+            .render_sql()
+            .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT `d`.`dict_code` FROM `bmbp_config_dict` `d` WHERE `d`.`dict_name` = ?"
+    );
+}
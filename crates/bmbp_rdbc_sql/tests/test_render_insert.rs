@@ -0,0 +1,146 @@
+use bmbp_rdbc_sql::dml::RdbcInsertBuilder;
+use bmbp_rdbc_sql::dql::RdbcQueryBuilder;
+use bmbp_rdbc_sql::filter::RdbcWhereFilterBuilder;
+use bmbp_rdbc_sql::select::RdbcSelectBuilder;
+use bmbp_rdbc_sql::table::RdbcTableBuilder;
+use bmbp_rdbc_sql::{RdbcInsertRender, RdbcMysqlDialect, RdbcSqlRender, RdbcSqliteDialect};
+use bmbp_rdbc_type::RdbcValue;
+
+#[test]
+pub fn test_render_insert() {
+    let mut insert_builder = RdbcInsertBuilder::new();
+    insert_builder
+        .table("bmbp_config_dict")
+        .insert_col_val("dict_code", "sex")
+        .insert_col_val("dict_name", "性别");
+    let (sql, values) = RdbcInsertRender::new(insert_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "INSERT INTO bmbp_config_dict (dict_code, dict_name) VALUES ($1, $2)"
+    );
+    assert_eq!(values.len(), 2);
+}
+
+#[test]
+pub fn test_render_sqlite_insert() {
+    let mut insert_builder = RdbcInsertBuilder::new();
+    insert_builder
+        .table("bmbp_config_dict")
+        .insert_col_val("dict_code", "sex")
+        .insert_col_val("data_enabled", false);
+    let (sql, values) = RdbcInsertRender::new_with_dialect(
+        insert_builder.build(),
+        RdbcSqliteDialect::new(3_039_000),
+    )
+    .render_sql()
+    .unwrap();
+    assert_eq!(
+        sql,
+        "INSERT INTO bmbp_config_dict (dict_code, data_enabled) VALUES (?1, ?2)"
+    );
+    assert!(matches!(values[1], RdbcValue::Int(0)));
+}
+
+#[test]
+pub fn test_render_insert_multi_values() {
+    let mut insert_builder = RdbcInsertBuilder::new();
+    insert_builder
+        .insert_table("bmbp_config_dict")
+        .insert_columns(vec!["dict_code", "dict_name"])
+        .insert_values(vec!["sex", "性别"])
+        .insert_values(vec!["nation", "民族"]);
+    let (sql, values) = RdbcInsertRender::new(insert_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "INSERT INTO bmbp_config_dict (dict_code, dict_name) VALUES ($1, $2), ($3, $4)"
+    );
+    assert_eq!(values.len(), 4);
+
+    let (sql, _) =
+        RdbcInsertRender::new_with_dialect(insert_builder.build(), RdbcMysqlDialect::default())
+            .render_sql()
+            .unwrap();
+    assert_eq!(
+        sql,
+        "INSERT INTO `bmbp_config_dict` (`dict_code`, `dict_name`) VALUES (?, ?), (?, ?)"
+    );
+
+    insert_builder.insert_values(vec!["sex"]);
+    assert!(RdbcInsertRender::new(insert_builder.build())
+        .render_sql()
+        .is_err());
+}
+
+#[test]
+pub fn test_render_insert_query() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("dict_code")
+        .select("dict_name")
+        .table("bmbp_config_dict_temp")
+        .eq_v("data_status", "Y");
+    let mut insert_builder = RdbcInsertBuilder::new();
+    insert_builder
+        .insert_table("bmbp_config_dict")
+        .insert_columns(vec!["dict_code", "dict_name"])
+        .insert_query(query_builder.build());
+    let (sql, values) = RdbcInsertRender::new(insert_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "INSERT INTO bmbp_config_dict (dict_code, dict_name) \
+         SELECT dict_code, dict_name FROM bmbp_config_dict_temp WHERE data_status = $1"
+    );
+    assert_eq!(values.len(), 1);
+}
+
+#[test]
+pub fn test_render_insert_column_expression() {
+    let mut insert_builder = RdbcInsertBuilder::new();
+    insert_builder
+        .insert_table("bmbp_config_dict")
+        .insert_col_val("dict_code", "sex")
+        .insert_col_col("dict_name", "dict_code");
+    let (sql, values) = RdbcInsertRender::new(insert_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "INSERT INTO bmbp_config_dict (dict_code, dict_name) VALUES ($1, dict_code)"
+    );
+    assert_eq!(values.len(), 1);
+
+    insert_builder.insert_column("dict_alias");
+    assert!(RdbcInsertRender::new(insert_builder.build())
+        .render_sql()
+        .is_err());
+}
+
+#[test]
+pub fn test_render_insert_alias() {
+    let mut insert_builder = RdbcInsertBuilder::new();
+    insert_builder
+        .table_as("bmbp_config_dict", "d")
+        .insert_col_val("dict_code", "sex");
+    let insert = insert_builder.build();
+    let (sql, _) = RdbcInsertRender::new(insert.clone()).render_sql().unwrap();
+    assert_eq!(
+        sql,
+        "INSERT INTO bmbp_config_dict AS d (dict_code) VALUES ($1)"
+    );
+    assert!(
+        RdbcInsertRender::new_with_dialect(insert.clone(), RdbcMysqlDialect::default())
+            .render_sql()
+            .is_err()
+    );
+    assert!(
+        RdbcInsertRender::new_with_dialect(insert, RdbcSqliteDialect::default())
+            .render_sql()
+            .is_err()
+    );
+}
@@ -0,0 +1,146 @@
+use bmbp_rdbc_sql::dql::RdbcQueryBuilder;
+use bmbp_rdbc_sql::filter::RdbcWhereFilterBuilder;
+use bmbp_rdbc_sql::limit::RdbcLimitBuilder;
+use bmbp_rdbc_sql::order::RdbcOrderBuilder;
+use bmbp_rdbc_sql::select::RdbcSelectBuilder;
+use bmbp_rdbc_sql::table::{RdbcJoinTableBuilder, RdbcTableBuilder};
+use bmbp_rdbc_sql::{
+    RdbcCompare, RdbcMysqlDialect, RdbcOracleDialect, RdbcQueryRender, RdbcSqlRender,
+    RdbcSqliteDialect,
+};
+
+#[test]
+pub fn test_render_join_on_eq() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("d.dict_code")
+        .select_as("p.dict_name", "parent_name")
+        .table_as("bmbp_config_dict", "d");
+    query_builder
+        .left_join_table_as("bmbp_config_dict", "p")
+        .on_eq("d.dict_parent_code", "p.dict_code")
+        .on_col("d.data_sort", RdbcCompare::GE, "p.data_sort")
+        .eq_v("p.data_status", "0");
+    query_builder.eq_col("d.dict_type", "p.dict_type");
+    let (sql, values) = RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT d.dict_code, p.dict_name AS parent_name FROM bmbp_config_dict d \
+         LEFT JOIN bmbp_config_dict p ON d.dict_parent_code = p.dict_code \
+         AND d.data_sort >= p.data_sort AND p.data_status = $1 \
+         WHERE d.dict_type = p.dict_type"
+    );
+    assert_eq!(values.len(), 1);
+}
+
+#[test]
+pub fn test_render_join_cross_natural_using() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("d.dict_code")
+        .table_as("bmbp_config_dict", "d");
+    query_builder.cross_join_table_as("bmbp_config_org", "o");
+    query_builder.natural_join_table("bmbp_config_dict_ext");
+    query_builder.natural_left_join_table_as("bmbp_config_dict_his", "h");
+    query_builder
+        .join_table_as("bmbp_config_dict_i18n", "i")
+        .using_vec(vec!["dict_code", "dict_type"]);
+    let sql = RdbcQueryRender::new(query_builder.build())
+        .render_raw()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT d.dict_code FROM bmbp_config_dict d CROSS JOIN bmbp_config_org o \
+         NATURAL JOIN bmbp_config_dict_ext NATURAL LEFT JOIN bmbp_config_dict_his h \
+         INNER JOIN bmbp_config_dict_i18n i USING (dict_code, dict_type)"
+    );
+}
+
+#[test]
+pub fn test_render_join_lateral() {
+    let mut latest_builder = RdbcQueryBuilder::new();
+    latest_builder
+        .select("l.login_time")
+        .table_as("bmbp_login_log", "l")
+        .eq_col("l.user_id", "u.user_id")
+        .order_desc("l.login_time");
+    latest_builder.limit(1);
+
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("u.user_name")
+        .select("t.login_time")
+        .table_as("bmbp_user", "u");
+    query_builder.lateral_join_table_query_as(latest_builder.build(), "t");
+    let sql = RdbcQueryRender::new(query_builder.build())
+        .render_raw()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT u.user_name, t.login_time FROM bmbp_user u CROSS JOIN LATERAL \
+         (SELECT l.login_time FROM bmbp_login_log l WHERE l.user_id = u.user_id \
+         ORDER BY l.login_time DESC LIMIT 1) t"
+    );
+
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("u.user_name")
+        .select("t.login_time")
+        .table_as("bmbp_user", "u");
+    query_builder.left_lateral_join_table_query_as(latest_builder.build(), "t");
+    let query = query_builder.build();
+    let sql = RdbcQueryRender::new_with_dialect(query.clone(), RdbcMysqlDialect::default())
+        .render_raw()
+        .unwrap();
+    assert!(sql.contains(" LEFT JOIN LATERAL (SELECT "));
+    assert!(sql.ends_with(" LIMIT 1) `t` ON 1 = 1"));
+    assert!(
+        RdbcQueryRender::new_with_dialect(query.clone(), RdbcMysqlDialect::new(8_000_013))
+            .render_raw()
+            .is_err()
+    );
+    let sql = RdbcQueryRender::new_with_dialect(query.clone(), RdbcOracleDialect::new(12))
+        .render_raw()
+        .unwrap();
+    assert!(sql.contains(" LEFT JOIN LATERAL (SELECT "));
+    assert!(
+        RdbcQueryRender::new_with_dialect(query.clone(), RdbcOracleDialect::new(11))
+            .render_raw()
+            .is_err()
+    );
+    assert!(
+        RdbcQueryRender::new_with_dialect(query, RdbcSqliteDialect::default())
+            .render_raw()
+            .is_err()
+    );
+}
+
+#[test]
+pub fn test_render_join_invalid() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder.table_as("bmbp_config_dict", "d");
+    query_builder
+        .cross_join_table_as("bmbp_config_org", "o")
+        .on_eq("d.org_code", "o.org_code");
+    assert!(RdbcQueryRender::new(query_builder.build())
+        .render_raw()
+        .is_err());
+
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder.table_as("bmbp_config_dict", "d");
+    query_builder
+        .join_table_as("bmbp_config_org", "o")
+        .on_eq("d.org_code", "o.org_code")
+        .using("org_code");
+    assert!(RdbcQueryRender::new(query_builder.build())
+        .render_raw()
+        .is_err());
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder.table_as("bmbp_config_dict", "d");
+    query_builder.left_join_table_as("bmbp_config_org", "o");
+    assert!(RdbcQueryRender::new(query_builder.build())
+        .render_raw()
+        .is_err());
+}
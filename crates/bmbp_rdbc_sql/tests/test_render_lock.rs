@@ -0,0 +1,156 @@
+use bmbp_rdbc_sql::dql::RdbcQueryBuilder;
+use bmbp_rdbc_sql::filter::RdbcWhereFilterBuilder;
+use bmbp_rdbc_sql::limit::RdbcLimitBuilder;
+use bmbp_rdbc_sql::lock::RdbcLockBuilder;
+use bmbp_rdbc_sql::order::RdbcOrderBuilder;
+use bmbp_rdbc_sql::select::RdbcSelectBuilder;
+use bmbp_rdbc_sql::table::RdbcTableBuilder;
+use bmbp_rdbc_sql::union::RdbcUnionBuilder;
+use bmbp_rdbc_sql::{
+    RdbcMysqlDialect, RdbcOracleDialect, RdbcQueryRender, RdbcSqlRender, RdbcSqliteDialect,
+};
+
+fn stock_query() -> RdbcQueryBuilder {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("stock_qty")
+        .table("bmbp_stock")
+        .eq_v("goods_id", "G001");
+    query_builder
+}
+
+#[test]
+pub fn test_render_lock_pg() {
+    let mut query_builder = stock_query();
+    query_builder.for_update();
+    let (sql, values) = RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT stock_qty FROM bmbp_stock WHERE goods_id = $1 FOR UPDATE"
+    );
+    assert_eq!(values.len(), 1);
+
+    let mut query_builder = stock_query();
+    query_builder.for_no_key_update().nowait();
+    let (sql, _) = RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT stock_qty FROM bmbp_stock WHERE goods_id = $1 FOR NO KEY UPDATE NOWAIT"
+    );
+
+    let mut query_builder = stock_query();
+    query_builder.for_key_share().lock_of("bmbp_stock");
+    let (sql, _) = RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT stock_qty FROM bmbp_stock WHERE goods_id = $1 FOR KEY SHARE OF bmbp_stock"
+    );
+}
+
+#[test]
+pub fn test_render_lock_queue() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("task_id")
+        .table("bmbp_task")
+        .eq_v("task_status", "0")
+        .order_asc("create_time");
+    query_builder.limit(10).skip_locked();
+    let (sql, _) = RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT task_id FROM bmbp_task WHERE task_status = $1 ORDER BY create_time ASC \
+         LIMIT 10 FOR UPDATE SKIP LOCKED"
+    );
+
+    let (sql, _) =
+        RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcMysqlDialect::default())
+            .render_sql()
+            .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT `task_id` FROM `bmbp_task` WHERE `task_status` = ? ORDER BY `create_time` ASC \
+         LIMIT 10 FOR UPDATE SKIP LOCKED"
+    );
+
+    assert!(
+        RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcOracleDialect::default())
+            .render_sql()
+            .is_err()
+    );
+    assert!(
+        RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcOracleDialect::new(11))
+            .render_sql()
+            .is_err()
+    );
+}
+
+#[test]
+pub fn test_render_lock_dialect() {
+    let mut query_builder = stock_query();
+    query_builder.for_share().lock_of("bmbp_stock");
+    let (sql, _) =
+        RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcMysqlDialect::default())
+            .render_sql()
+            .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT `stock_qty` FROM `bmbp_stock` WHERE `goods_id` = ? FOR SHARE OF `bmbp_stock`"
+    );
+
+    let mut query_builder = stock_query();
+    query_builder.for_key_share();
+    assert!(
+        RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcMysqlDialect::default())
+            .render_sql()
+            .is_err()
+    );
+
+    let mut query_builder = stock_query();
+    query_builder.for_update().nowait();
+    let (sql, _) =
+        RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcOracleDialect::default())
+            .render_sql()
+            .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT \"stock_qty\" FROM \"bmbp_stock\" WHERE \"goods_id\" = :1 FOR UPDATE NOWAIT"
+    );
+    query_builder.lock_of("bmbp_stock");
+    assert!(
+        RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcOracleDialect::default())
+            .render_sql()
+            .is_err()
+    );
+
+    let mut query_builder = stock_query();
+    query_builder.for_update();
+    assert!(
+        RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcSqliteDialect::default())
+            .render_sql()
+            .is_err()
+    );
+    assert!(query_builder.lock_take().is_some());
+    assert!(
+        RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcSqliteDialect::default())
+            .render_sql()
+            .is_ok()
+    );
+}
+
+#[test]
+pub fn test_render_lock_union() {
+    let mut query_builder = stock_query();
+    query_builder.union(stock_query().build()).for_update();
+    assert!(RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .is_err());
+}
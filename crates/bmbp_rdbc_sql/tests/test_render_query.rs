@@ -0,0 +1,405 @@
+use bmbp_rdbc_sql::dql::RdbcQueryBuilder;
+use bmbp_rdbc_sql::filter::RdbcWhereFilterBuilder;
+use bmbp_rdbc_sql::group::RdbcGroupBuilder;
+use bmbp_rdbc_sql::having::RdbcHavingBuilder;
+use bmbp_rdbc_sql::limit::{RdbcLimitBuilder, RdbcOffsetBuilder};
+use bmbp_rdbc_sql::order::RdbcOrderBuilder;
+use bmbp_rdbc_sql::select::RdbcSelectBuilder;
+use bmbp_rdbc_sql::table::{RdbcJoinTableBuilder, RdbcTableBuilder};
+use bmbp_rdbc_sql::union::{RdbcUnionAllBuilder, RdbcUnionBuilder};
+use bmbp_rdbc_sql::with::RdbcWithBuilder;
+use bmbp_rdbc_sql::{
+    RdbcColumn, RdbcCompare, RdbcMysqlDialect, RdbcOracleDialect, RdbcQueryRender,
+    RdbcSimpleColumn, RdbcSqlDialect, RdbcSqlRender, RdbcSqliteDialect,
+};
+use bmbp_rdbc_type::RdbcValue;
+
+#[test]
+pub fn test_render_simple_query() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("dict_code")
+        .select_as("dict_name", "name")
+        .table("bmbp_config_dict");
+    query_builder
+        .eq_v("data_status", "Y")
+        .like_v("dict_name", "字典");
+    let (sql, values) = RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT dict_code, dict_name AS name FROM bmbp_config_dict WHERE data_status = $1 AND dict_name LIKE '%' || $2 || '%'"
+    );
+    assert_eq!(values.len(), 2);
+    assert_eq!(values[0].to_string(), "Y");
+    assert_eq!(values[1].to_string(), "字典");
+}
+
+#[test]
+pub fn test_render_query_clauses() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("dict_type")
+        .table_as("bmbp_config_dict", "d");
+    query_builder
+        .left_join_table_as("bmbp_config_dict", "p")
+        .eq_raw("p.dict_code", "d.dict_parent_code");
+    query_builder
+        .in_v("d.dict_type", vec!["A", "B"])
+        .between_v("d.data_sort", 1, 10);
+    query_builder.group_by("dict_type");
+    query_builder
+        .having_mut()
+        .raw_column_v("count(1)", RdbcCompare::GT, 1);
+    query_builder.order_desc(RdbcColumn::SimpleColumn(RdbcSimpleColumn {
+        column: "dict_type".to_string(),
+    }));
+    query_builder.limit(10).offset(20);
+    let (sql, values) = RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT dict_type FROM bmbp_config_dict d LEFT JOIN bmbp_config_dict p ON p.dict_code = d.dict_parent_code \
+         WHERE d.dict_type IN ($1, $2) AND d.data_sort BETWEEN $3 AND $4 \
+         GROUP BY dict_type HAVING count(1) > $5 ORDER BY dict_type DESC LIMIT 10 OFFSET 20"
+    );
+    assert_eq!(values.len(), 5);
+}
+
+#[test]
+pub fn test_render_sub_query_and_union() {
+    let mut sub_query = RdbcQueryBuilder::new();
+    sub_query
+        .select("dict_code")
+        .table("bmbp_config_dict")
+        .eq_v("dict_type", "A");
+
+    let mut union_query = RdbcQueryBuilder::new();
+    union_query
+        .select("dict_code")
+        .table("bmbp_config_dict_his")
+        .eq_v("dict_type", "B");
+
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("dict_code")
+        .table("bmbp_config_dict")
+        .eq_v("data_status", "Y")
+        .in_query("dict_parent_code", sub_query.build());
+    query_builder.union(union_query.build());
+    let (sql, values) = RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "(SELECT dict_code FROM bmbp_config_dict WHERE data_status = $1 AND dict_parent_code IN \
+         (SELECT dict_code FROM bmbp_config_dict WHERE dict_type = $2)) \
+         UNION (SELECT dict_code FROM bmbp_config_dict_his WHERE dict_type = $3)"
+    );
+    assert!(matches!(&values[2], RdbcValue::Varchar(v) if v == "B"));
+}
+
+#[test]
+pub fn test_render_mysql_query() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("d.dict_code")
+        .select_as("dict_name", "name")
+        .select_raw(1)
+        .table_as("bmbp_config_dict", "d");
+    query_builder
+        .eq_v("d.data_status", "Y")
+        .like_left_v("dict_name", "典")
+        .like_right_v("dict_name", "字");
+    query_builder.limit(10).offset(20);
+    let (sql, values) =
+        RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcMysqlDialect::default())
+            .render_sql()
+            .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT `d`.`dict_code`, `dict_name` AS `name`, 1 FROM `bmbp_config_dict` `d` \
+         WHERE `d`.`data_status` = ? AND `dict_name` LIKE CONCAT('%', ?) AND `dict_name` LIKE CONCAT(?, '%') \
+         LIMIT 20, 10"
+    );
+    assert_eq!(values.len(), 3);
+
+    let mut offset_builder = RdbcQueryBuilder::new();
+    offset_builder.table("bmbp_config_dict").offset(5);
+    let (sql, _) =
+        RdbcQueryRender::new_with_dialect(offset_builder.build(), RdbcMysqlDialect::default())
+            .render_sql()
+            .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT * FROM `bmbp_config_dict` LIMIT 5, 18446744073709551615"
+    );
+}
+
+#[test]
+pub fn test_render_sqlite_query() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("dict_code")
+        .table("bmbp_config_dict")
+        .eq_v("data_status", true)
+        .like_v("dict_name", "典")
+        .offset(20);
+    let (sql, values) =
+        RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcSqliteDialect::new(3_038_000))
+            .render_sql()
+            .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT dict_code FROM bmbp_config_dict WHERE data_status = ?1 \
+         AND dict_name LIKE '%' || ?2 || '%' LIMIT -1 OFFSET 20"
+    );
+    assert!(matches!(values[0], RdbcValue::Int(1)));
+
+    let mut join_builder = RdbcQueryBuilder::new();
+    join_builder.table_as("bmbp_config_dict", "d");
+    join_builder
+        .right_join_table_as("bmbp_config_dict", "p")
+        .on_eq("d.dict_parent_code", "p.dict_code");
+    let join_query = join_builder.build();
+    assert!(RdbcQueryRender::new_with_dialect(
+        join_query.clone(),
+        RdbcSqliteDialect::new(3_038_000)
+    )
+    .render_sql()
+    .is_err());
+    let (sql, _) = RdbcQueryRender::new_with_dialect(
+        join_query,
+        RdbcSqliteDialect::new(RdbcSqliteDialect::OUTER_JOIN_VERSION),
+    )
+    .render_sql()
+    .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT * FROM bmbp_config_dict d RIGHT JOIN bmbp_config_dict p \
+         ON d.dict_parent_code = p.dict_code"
+    );
+}
+
+#[test]
+pub fn test_render_oracle_query() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("d.dict_code")
+        .table_as("bmbp_config_dict", "d")
+        .eq_v("d.data_status", "Y")
+        .eq_v("d.dict_remark", "")
+        .ne_v("d.dict_alias", "");
+    query_builder.limit(10).offset(20);
+    let query = query_builder.build();
+
+    let (sql, values) =
+        RdbcQueryRender::new_with_dialect(query.clone(), RdbcOracleDialect::new(12))
+            .render_sql()
+            .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT \"d\".\"dict_code\" FROM \"bmbp_config_dict\" \"d\" WHERE \"d\".\"data_status\" = :1 \
+         AND \"d\".\"dict_remark\" IS NULL AND \"d\".\"dict_alias\" IS NOT NULL \
+         OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY"
+    );
+    assert_eq!(values.len(), 1);
+
+    let (sql, _) = RdbcQueryRender::new_with_dialect(query, RdbcOracleDialect::new(11))
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT * FROM (SELECT t_.*, ROWNUM rn_ FROM (SELECT \"d\".\"dict_code\" FROM \"bmbp_config_dict\" \"d\" \
+         WHERE \"d\".\"data_status\" = :1 AND \"d\".\"dict_remark\" IS NULL AND \"d\".\"dict_alias\" IS NOT NULL) t_ \
+         WHERE ROWNUM <= 30) WHERE rn_ > 20"
+    );
+
+    let mut limit_builder = RdbcQueryBuilder::new();
+    limit_builder.table("bmbp_config_dict").limit(10);
+    let (sql, _) =
+        RdbcQueryRender::new_with_dialect(limit_builder.build(), RdbcOracleDialect::new(11))
+            .render_sql()
+            .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT * FROM (SELECT * FROM \"bmbp_config_dict\") WHERE ROWNUM <= 10"
+    );
+}
+
+/// 第三方方言，仅需实现差异部分
+struct RdbcSqlServerDialect;
+
+impl RdbcSqlDialect for RdbcSqlServerDialect {
+    fn quote_ident(&self, ident: &str) -> String {
+        format!("[{}]", ident)
+    }
+    fn placeholder(&self, index: usize) -> String {
+        format!("@p{}", index)
+    }
+    fn concat(&self, items: &[&str]) -> String {
+        items.join(" + ")
+    }
+    fn render_page(&self, sql: String, limit: Option<u64>, offset: Option<u64>) -> String {
+        match limit {
+            Some(limit) => format!(
+                "{} OFFSET {} ROWS FETCH NEXT {} ROWS ONLY",
+                sql,
+                offset.unwrap_or(0),
+                limit
+            ),
+            None => sql,
+        }
+    }
+}
+
+#[test]
+pub fn test_render_custom_dialect_query() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("dict_code")
+        .table("bmbp_config_dict")
+        .like_v("dict_name", "典");
+    query_builder.limit(10);
+    let (sql, values) =
+        RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcSqlServerDialect)
+            .render_sql()
+            .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT [dict_code] FROM [bmbp_config_dict] WHERE [dict_name] LIKE '%' + @p1 + '%' \
+         OFFSET 0 ROWS FETCH NEXT 10 ROWS ONLY"
+    );
+    assert_eq!(values.len(), 1);
+}
+
+#[test]
+pub fn test_render_quote_ident() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("order")
+        .select("u.user")
+        .table_as("bmbp.bmbp_user", "u")
+        .eq_v("u.name", "admin");
+    let query = query_builder.build();
+    let (sql, _) = RdbcQueryRender::new(query.clone()).render_sql().unwrap();
+    assert_eq!(
+        sql,
+        "SELECT \"order\", u.\"user\" FROM bmbp.bmbp_user u WHERE u.name = $1"
+    );
+    let (sql, _) = RdbcQueryRender::new_with_dialect(query, RdbcMysqlDialect::default())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT `order`, `u`.`user` FROM `bmbp`.`bmbp_user` `u` WHERE `u`.`name` = ?"
+    );
+
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("dict_code; DROP TABLE bmbp_user")
+        .table("bmbp_config_dict");
+    let err = RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .unwrap_err();
+    assert!(err.message().contains("非法标识符"));
+
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder.select("*").table("bmbp_config_dict");
+    query_builder.eq_v("dict_code`", "sex");
+    assert!(
+        RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcMysqlDialect::default())
+            .render_sql()
+            .is_err()
+    );
+
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder.raw_table_as("generate_series(1, 3)", "s");
+    query_builder.raw_column_v("s.x + 1", RdbcCompare::GT, 2);
+    let (sql, _) = RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT * FROM generate_series(1, 3) s WHERE s.x + 1 > $1"
+    );
+}
+
+#[test]
+pub fn test_render_with_recursive() {
+    let columns = vec!["dict_code", "dict_parent_code", "dict_code_path"];
+    let mut anchor_builder = RdbcQueryBuilder::new();
+    anchor_builder
+        .select("dict_code")
+        .select("dict_parent_code")
+        .select("dict_code_path")
+        .table("bmbp_config_dict")
+        .eq_v("dict_code", "sex");
+    let mut child_builder = RdbcQueryBuilder::new();
+    child_builder
+        .select("d.dict_code")
+        .select("d.dict_parent_code")
+        .select("d.dict_code_path")
+        .table_as("bmbp_config_dict", "d");
+    child_builder
+        .join_table_as("dict_tree", "t")
+        .eq_raw("d.dict_parent_code", "t.dict_code");
+    anchor_builder.union_all(child_builder.build());
+
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .with_recursive("dict_tree", columns.clone(), anchor_builder.build())
+        .table("dict_tree");
+    let query = query_builder.build();
+    let (sql, values) = RdbcQueryRender::new(query.clone()).render_sql().unwrap();
+    assert_eq!(
+        sql,
+        "WITH RECURSIVE dict_tree (dict_code, dict_parent_code, dict_code_path) AS (\
+         (SELECT dict_code, dict_parent_code, dict_code_path FROM bmbp_config_dict \
+         WHERE dict_code = $1) UNION ALL (SELECT d.dict_code, d.dict_parent_code, \
+         d.dict_code_path FROM bmbp_config_dict d INNER JOIN dict_tree t \
+         ON d.dict_parent_code = t.dict_code)) SELECT * FROM dict_tree"
+    );
+    assert_eq!(values.len(), 1);
+
+    let (sql, _) = RdbcQueryRender::new_with_dialect(query, RdbcOracleDialect::default())
+        .render_sql()
+        .unwrap();
+    assert!(sql.starts_with(
+        "WITH \"dict_tree\" (\"dict_code\", \"dict_parent_code\", \"dict_code_path\") AS ("
+    ));
+
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .with_recursive::<_, String>("dict_tree", vec![], anchor_builder.build())
+        .table("dict_tree");
+    assert!(
+        RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcOracleDialect::default())
+            .render_sql()
+            .is_err()
+    );
+}
+
+#[test]
+pub fn test_render_with() {
+    let mut sub_builder = RdbcQueryBuilder::new();
+    sub_builder.table("bmbp_config_dict").eq_v("dict_type", "A");
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .with("dict_a", sub_builder.build())
+        .select("dict_code")
+        .table("dict_a");
+    query_builder.eq_v("dict_code", "sex");
+    let (sql, values) =
+        RdbcQueryRender::new_with_dialect(query_builder.build(), RdbcMysqlDialect::default())
+            .render_sql()
+            .unwrap();
+    assert_eq!(
+        sql,
+        "WITH `dict_a` AS (SELECT * FROM `bmbp_config_dict` WHERE `dict_type` = ?) \
+         SELECT `dict_code` FROM `dict_a` WHERE `dict_code` = ?"
+    );
+    assert_eq!(values.len(), 2);
+}
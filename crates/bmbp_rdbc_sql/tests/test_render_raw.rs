@@ -0,0 +1,139 @@
+use bmbp_rdbc_sql::dml::{RdbcDeleteBuilder, RdbcInsertBuilder, RdbcUpdateBuilder};
+use bmbp_rdbc_sql::dql::RdbcQueryBuilder;
+use bmbp_rdbc_sql::filter::RdbcWhereFilterBuilder;
+use bmbp_rdbc_sql::select::RdbcSelectBuilder;
+use bmbp_rdbc_sql::table::RdbcTableBuilder;
+use bmbp_rdbc_sql::{
+    RdbcDeleteRender, RdbcInsertRender, RdbcMysqlDialect, RdbcOracleDialect, RdbcPostgresDialect,
+    RdbcQueryRender, RdbcScriptRender, RdbcSqlDialect, RdbcSqlRender, RdbcSqliteDialect,
+    RdbcUpdateRender,
+};
+use bmbp_rdbc_type::{RdbcValue, RdbcValueIdent};
+use std::collections::HashMap;
+
+#[test]
+pub fn test_render_raw_query() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("dict_code")
+        .select_raw("it's")
+        .table("bmbp_config_dict")
+        .eq_v("dict_name", "x' OR '1'='1")
+        .in_v("dict_level", vec![1, 2]);
+    let raw = RdbcQueryRender::new(query_builder.build())
+        .render_raw()
+        .unwrap();
+    assert_eq!(
+        raw,
+        "SELECT dict_code, 'it''s' FROM bmbp_config_dict \
+         WHERE dict_name = 'x'' OR ''1''=''1' AND dict_level IN (1, 2)"
+    );
+}
+
+#[test]
+pub fn test_render_raw_dml() {
+    let mut insert_builder = RdbcInsertBuilder::new();
+    insert_builder
+        .insert_table("bmbp_config_dict")
+        .insert_col_val("dict_name", "a\\b")
+        .insert_col_val("dict_valid", true);
+    let raw =
+        RdbcInsertRender::new_with_dialect(insert_builder.build(), RdbcMysqlDialect::default())
+            .render_raw()
+            .unwrap();
+    assert_eq!(
+        raw,
+        "INSERT INTO `bmbp_config_dict` (`dict_name`, `dict_valid`) VALUES ('a\\\\b', TRUE)"
+    );
+
+    let mut update_builder = RdbcUpdateBuilder::new();
+    update_builder
+        .table("bmbp_config_dict")
+        .set("dict_valid", false);
+    update_builder.eq_v("dict_code", "sex");
+    let raw =
+        RdbcUpdateRender::new_with_dialect(update_builder.build(), RdbcSqliteDialect::default())
+            .render_raw()
+            .unwrap();
+    assert_eq!(
+        raw,
+        "UPDATE bmbp_config_dict SET dict_valid = 0 WHERE dict_code = 'sex'"
+    );
+
+    let mut delete_builder = RdbcDeleteBuilder::new();
+    delete_builder.table("bmbp_config_dict");
+    delete_builder.eq_v("dict_code", "sex");
+    let raw = RdbcDeleteRender::new(delete_builder.build())
+        .render_raw()
+        .unwrap();
+    assert_eq!(raw, "DELETE FROM bmbp_config_dict WHERE dict_code = 'sex'");
+}
+
+#[test]
+pub fn test_render_raw_script() {
+    let mut params = HashMap::new();
+    params.insert("code".to_string(), RdbcValue::from("o'neil"));
+    params.insert("valid".to_string(), RdbcValue::Null);
+    let raw = RdbcScriptRender::new(
+        "SELECT * FROM t WHERE code = #{code} AND note <> '#{code}' AND valid IS #{valid}"
+            .to_string(),
+        params,
+    )
+    .render_raw()
+    .unwrap();
+    assert_eq!(
+        raw,
+        "SELECT * FROM t WHERE code = 'o''neil' AND note <> '#{code}' AND valid IS NULL"
+    );
+}
+
+#[test]
+pub fn test_render_literal() {
+    let date = RdbcValue::Date(chrono::NaiveDate::from_ymd_opt(2024, 5, 1).unwrap());
+    let bytes = RdbcValue::Bytes(vec![0xde, 0xad]);
+    let array = RdbcValue::Array(vec![RdbcValue::from("a"), RdbcValue::Int(1)]);
+    let boolean = RdbcValue::Boolean(true);
+
+    let pg = RdbcPostgresDialect;
+    assert_eq!(pg.literal(&date).unwrap(), "DATE '2024-05-01'");
+    assert_eq!(pg.literal(&bytes).unwrap(), "'\\xDEAD'::bytea");
+    assert_eq!(pg.literal(&array).unwrap(), "ARRAY['a', 1]");
+    assert_eq!(pg.literal(&RdbcValue::Array(vec![])).unwrap(), "'{}'");
+    assert_eq!(pg.literal(&boolean).unwrap(), "TRUE");
+    assert_eq!(pg.literal(&RdbcValue::Null).unwrap(), "NULL");
+    assert!(pg.literal(&RdbcValue::BigDouble(f64::NAN)).is_err());
+    assert!(pg.literal(&RdbcValue::Double(f32::INFINITY)).is_err());
+    assert_eq!(
+        pg.literal(&u64::MAX.value()).unwrap(),
+        "18446744073709551615"
+    );
+    assert_eq!(
+        pg.literal(&i128::MIN.value()).unwrap(),
+        i128::MIN.to_string()
+    );
+    assert!(pg
+        .literal(&RdbcValue::Decimal("1; DROP TABLE t".to_string()))
+        .is_err());
+
+    let mysql = RdbcMysqlDialect::default();
+    assert_eq!(
+        mysql.literal(&RdbcValue::from("a'\\")).unwrap(),
+        "'a''\\\\'"
+    );
+    assert_eq!(mysql.literal(&bytes).unwrap(), "X'DEAD'");
+    assert_eq!(mysql.literal(&array).unwrap(), "('a', 1)");
+
+    let sqlite = RdbcSqliteDialect::default();
+    assert_eq!(sqlite.literal(&date).unwrap(), "'2024-05-01'");
+    assert_eq!(sqlite.literal(&boolean).unwrap(), "1");
+
+    let oracle = RdbcOracleDialect::default();
+    assert_eq!(oracle.literal(&date).unwrap(), "DATE '2024-05-01'");
+    assert_eq!(oracle.literal(&bytes).unwrap(), "HEXTORAW('DEAD')");
+
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder.table("t").eq_v("a", f64::NAN);
+    let query = query_builder.build();
+    assert!(RdbcQueryRender::new(query.clone()).render_raw().is_err());
+    assert!(RdbcQueryRender::new(query).render_sql().is_ok());
+}
@@ -0,0 +1,231 @@
+use bmbp_rdbc_sql::dml::{RdbcDeleteBuilder, RdbcInsertBuilder, RdbcUpdateBuilder};
+use bmbp_rdbc_sql::filter::RdbcWhereFilterBuilder;
+use bmbp_rdbc_sql::returning::RdbcReturningBuilder;
+use bmbp_rdbc_sql::table::RdbcTableBuilder;
+use bmbp_rdbc_sql::{
+    RdbcDeleteRender, RdbcInsertRender, RdbcMysqlDialect, RdbcOracleDialect, RdbcReturningSql,
+    RdbcSqlRender, RdbcSqliteDialect, RdbcUpdateRender,
+};
+
+#[test]
+pub fn test_render_insert_returning() {
+    let mut insert_builder = RdbcInsertBuilder::new();
+    insert_builder
+        .insert_table("bmbp_config_dict")
+        .insert_col_val("dict_code", "sex")
+        .returning_vec(vec!["data_id", "data_create_time"]);
+    let (sql, values) = RdbcInsertRender::new(insert_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "INSERT INTO bmbp_config_dict (dict_code) VALUES ($1) RETURNING data_id, data_create_time"
+    );
+    assert_eq!(values.len(), 1);
+
+    let returning = RdbcInsertRender::new(insert_builder.build())
+        .render_returning_sql()
+        .unwrap();
+    assert!(matches!(returning, RdbcReturningSql::Returning(_, _)));
+}
+
+#[test]
+pub fn test_render_upsert_returning() {
+    let mut insert_builder = RdbcInsertBuilder::new();
+    insert_builder
+        .insert_table("bmbp_config_dict")
+        .insert_col_val("dict_code", "sex")
+        .insert_col_val("dict_name", "性别")
+        .on_conflict("dict_code")
+        .do_update("dict_name")
+        .returning_all();
+    let (sql, _) = RdbcInsertRender::new_with_dialect(
+        insert_builder.build(),
+        RdbcSqliteDialect::new(3_039_000),
+    )
+    .render_sql()
+    .unwrap();
+    assert_eq!(
+        sql,
+        "INSERT INTO bmbp_config_dict (dict_code, dict_name) VALUES (?1, ?2) \
+         ON CONFLICT (dict_code) DO UPDATE SET dict_name = EXCLUDED.dict_name RETURNING *"
+    );
+}
+
+#[test]
+pub fn test_render_insert_returning_unsupported() {
+    let mut insert_builder = RdbcInsertBuilder::new();
+    insert_builder
+        .insert_table("bmbp_config_dict")
+        .insert_col_val("dict_code", "sex")
+        .returning("data_id");
+    assert!(RdbcInsertRender::new_with_dialect(
+        insert_builder.build(),
+        RdbcMysqlDialect::default()
+    )
+    .render_sql()
+    .is_err());
+    // 未指定主键列时无法回查
+    assert!(RdbcInsertRender::new_with_dialect(
+        insert_builder.build(),
+        RdbcMysqlDialect::default()
+    )
+    .render_returning_sql()
+    .is_err());
+}
+
+#[test]
+pub fn test_render_insert_returning_last_insert_id() {
+    let mut insert_builder = RdbcInsertBuilder::new();
+    insert_builder
+        .insert_table("bmbp_config_dict")
+        .insert_col_val("dict_code", "sex")
+        .returning_vec(vec!["data_id", "data_create_time"])
+        .returning_key("data_id");
+    let returning =
+        RdbcInsertRender::new_with_dialect(insert_builder.build(), RdbcMysqlDialect::default())
+            .render_returning_sql()
+            .unwrap();
+    match returning {
+        RdbcReturningSql::ExecuteThenQuery {
+            execute_sql,
+            execute_params,
+            query_sql,
+            query_params,
+        } => {
+            assert_eq!(
+                execute_sql,
+                "INSERT INTO `bmbp_config_dict` (`dict_code`) VALUES (?)"
+            );
+            assert_eq!(execute_params.len(), 1);
+            assert_eq!(
+                query_sql,
+                "SELECT `data_id`, `data_create_time` FROM `bmbp_config_dict` WHERE `data_id` = LAST_INSERT_ID()"
+            );
+            assert!(query_params.is_empty());
+        }
+        _ => panic!("MySQL 应回查插入行"),
+    }
+
+    let returning = RdbcInsertRender::new_with_dialect(
+        insert_builder.build(),
+        RdbcSqliteDialect::new(3_034_000),
+    )
+    .render_returning_sql()
+    .unwrap();
+    match returning {
+        RdbcReturningSql::ExecuteThenQuery { query_sql, .. } => assert_eq!(
+            query_sql,
+            "SELECT data_id, data_create_time FROM bmbp_config_dict WHERE data_id = last_insert_rowid()"
+        ),
+        _ => panic!("SQLite 3.34 应回查插入行"),
+    }
+}
+
+#[test]
+pub fn test_render_insert_returning_explicit_key() {
+    let mut insert_builder = RdbcInsertBuilder::new();
+    insert_builder
+        .insert_table("bmbp_config_dict")
+        .insert_columns(vec!["data_id", "dict_code"])
+        .insert_values(vec!["d1", "sex"])
+        .returning_all()
+        .returning_key("data_id");
+    let returning =
+        RdbcInsertRender::new_with_dialect(insert_builder.build(), RdbcOracleDialect::default())
+            .render_returning_sql()
+            .unwrap();
+    match returning {
+        RdbcReturningSql::ExecuteThenQuery {
+            execute_sql,
+            query_sql,
+            query_params,
+            ..
+        } => {
+            assert_eq!(
+                execute_sql,
+                "INSERT INTO \"bmbp_config_dict\" (\"data_id\", \"dict_code\") VALUES (:1, :2)"
+            );
+            assert_eq!(
+                query_sql,
+                "SELECT * FROM \"bmbp_config_dict\" WHERE \"data_id\" = :1"
+            );
+            assert_eq!(query_params.len(), 1);
+        }
+        _ => panic!("Oracle 应按主键回查插入行"),
+    }
+
+    // 多行插入无法回查
+    insert_builder.insert_values(vec!["d2", "nation"]);
+    assert!(RdbcInsertRender::new_with_dialect(
+        insert_builder.build(),
+        RdbcOracleDialect::default(),
+    )
+    .render_returning_sql()
+    .is_err());
+}
+
+#[test]
+pub fn test_render_update_returning() {
+    let mut update_builder = RdbcUpdateBuilder::new();
+    update_builder
+        .table("bmbp_config_dict")
+        .set("dict_name", "性别")
+        .returning("data_update_time");
+    update_builder.eq_v("dict_code", "sex");
+    let (sql, values) = RdbcUpdateRender::new(update_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "UPDATE bmbp_config_dict SET dict_name = $1 WHERE dict_code = $2 RETURNING data_update_time"
+    );
+    assert_eq!(values.len(), 2);
+    assert!(RdbcUpdateRender::new_with_dialect(
+        update_builder.build(),
+        RdbcMysqlDialect::default()
+    )
+    .render_returning_sql()
+    .is_err());
+}
+
+#[test]
+pub fn test_render_delete_returning() {
+    let mut delete_builder = RdbcDeleteBuilder::new();
+    delete_builder
+        .table("bmbp_config_dict")
+        .returning_vec(vec!["data_id", "dict_code"]);
+    delete_builder.eq_v("dict_code", "sex");
+    let (sql, _) = RdbcDeleteRender::new(delete_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "DELETE FROM bmbp_config_dict WHERE dict_code = $1 RETURNING data_id, dict_code"
+    );
+
+    let returning =
+        RdbcDeleteRender::new_with_dialect(delete_builder.build(), RdbcMysqlDialect::default())
+            .render_returning_sql()
+            .unwrap();
+    match returning {
+        RdbcReturningSql::QueryThenExecute {
+            query_sql,
+            query_params,
+            execute_sql,
+            execute_params,
+        } => {
+            assert_eq!(
+                query_sql,
+                "SELECT `data_id`, `dict_code` FROM `bmbp_config_dict` WHERE `dict_code` = ? FOR UPDATE"
+            );
+            assert_eq!(query_params.len(), 1);
+            assert_eq!(
+                execute_sql,
+                "DELETE FROM `bmbp_config_dict` WHERE `dict_code` = ?"
+            );
+            assert_eq!(execute_params.len(), 1);
+        }
+        _ => panic!("MySQL 应先查询待删除行"),
+    }
+}
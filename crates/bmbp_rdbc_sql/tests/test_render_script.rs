@@ -0,0 +1,81 @@
+use bmbp_rdbc_sql::dml::RdbcUpdateBuilder;
+use bmbp_rdbc_sql::dql::RdbcQueryBuilder;
+use bmbp_rdbc_sql::filter::RdbcWhereFilterBuilder;
+use bmbp_rdbc_sql::select::RdbcSelectBuilder;
+use bmbp_rdbc_sql::table::RdbcTableBuilder;
+use bmbp_rdbc_sql::{
+    RdbcMysqlDialect, RdbcQueryRender, RdbcScriptRender, RdbcSqlRender, RdbcUpdateRender,
+};
+use bmbp_rdbc_type::RdbcValue;
+use std::collections::HashMap;
+
+#[test]
+pub fn test_render_query_script() {
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("dict_code")
+        .table_as("bmbp_config_dict", "d")
+        .eq_v("d.dict_code", "sex")
+        .ne_v("d.dict_code", "nation")
+        .like_v("dict_name", "性");
+    let query = query_builder.build();
+    let (script, params) = RdbcQueryRender::new(query.clone()).render_script().unwrap();
+    assert_eq!(
+        script,
+        "SELECT dict_code FROM bmbp_config_dict d WHERE d.dict_code = #{p_dict_code_1} \
+         AND d.dict_code <> #{p_dict_code_2} AND dict_name LIKE '%' || #{p_dict_name_1} || '%'"
+    );
+    assert_eq!(params.len(), 3);
+    assert!(matches!(params.get("p_dict_code_2"), Some(RdbcValue::Varchar(v)) if v == "nation"));
+
+    let (script, params) = RdbcQueryRender::new_with_dialect(query, RdbcMysqlDialect::default())
+        .render_script()
+        .unwrap();
+    let (sql, values) =
+        RdbcScriptRender::new_with_dialect(script, params, RdbcMysqlDialect::default())
+            .render_sql()
+            .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT `dict_code` FROM `bmbp_config_dict` `d` WHERE `d`.`dict_code` = ? \
+         AND `d`.`dict_code` <> ? AND `dict_name` LIKE CONCAT('%', ?, '%')"
+    );
+    assert!(matches!(&values[1], RdbcValue::Varchar(v) if v == "nation"));
+}
+
+#[test]
+pub fn test_render_update_script() {
+    let mut update_builder = RdbcUpdateBuilder::new();
+    update_builder
+        .table("bmbp_config_dict")
+        .set("dict_name", "性别");
+    update_builder.eq_script("dict_code", "code");
+    let (script, mut params) = RdbcUpdateRender::new(update_builder.build())
+        .render_script()
+        .unwrap();
+    assert_eq!(
+        script,
+        "UPDATE bmbp_config_dict SET dict_name = #{p_dict_name_1} WHERE dict_code = #{code}"
+    );
+    assert!(RdbcScriptRender::new(script.clone(), params.clone())
+        .render_sql()
+        .is_err());
+
+    params.insert("code".to_string(), RdbcValue::from("sex"));
+    let (sql, values) = RdbcScriptRender::new(script, params).render_sql().unwrap();
+    assert_eq!(
+        sql,
+        "UPDATE bmbp_config_dict SET dict_name = $1 WHERE dict_code = $2"
+    );
+    assert_eq!(values.len(), 2);
+}
+
+#[test]
+pub fn test_render_script_literal() {
+    let mut params = HashMap::new();
+    params.insert("name".to_string(), RdbcValue::from("性别"));
+    let script = "SELECT '#{name}', 'it''s' FROM t WHERE name = #{ name }".to_string();
+    let (sql, values) = RdbcScriptRender::new(script, params).render_sql().unwrap();
+    assert_eq!(sql, "SELECT '#{name}', 'it''s' FROM t WHERE name = $1");
+    assert_eq!(values.len(), 1);
+}
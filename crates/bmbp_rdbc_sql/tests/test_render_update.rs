@@ -0,0 +1,150 @@
+use bmbp_rdbc_sql::dml::RdbcUpdateBuilder;
+use bmbp_rdbc_sql::filter::RdbcWhereFilterBuilder;
+use bmbp_rdbc_sql::limit::RdbcLimitBuilder;
+use bmbp_rdbc_sql::table::{RdbcJoinTableBuilder, RdbcTableBuilder};
+use bmbp_rdbc_sql::{
+    RdbcMysqlDialect, RdbcOracleDialect, RdbcPostgresDialect, RdbcSqlRender, RdbcSqliteDialect,
+    RdbcUpdateRender,
+};
+use bmbp_rdbc_type::RdbcValue;
+
+#[test]
+pub fn test_render_update() {
+    let mut update_builder = RdbcUpdateBuilder::new();
+    update_builder
+        .table("bmbp_config_dict")
+        .set("dict_name", "性别")
+        .set("data_status", "Y");
+    update_builder.eq_v("dict_code", "sex");
+    let (sql, values) = RdbcUpdateRender::new(update_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "UPDATE bmbp_config_dict SET dict_name = $1, data_status = $2 WHERE dict_code = $3"
+    );
+    assert_eq!(values.len(), 3);
+}
+
+#[test]
+pub fn test_render_sqlite_update() {
+    let mut update_builder = RdbcUpdateBuilder::new();
+    update_builder
+        .table("bmbp_config_dict")
+        .set("data_enabled", true);
+    update_builder.eq_v("dict_code", "sex");
+    let (sql, values) = RdbcUpdateRender::new_with_dialect(
+        update_builder.build(),
+        RdbcSqliteDialect::new(3_039_000),
+    )
+    .render_sql()
+    .unwrap();
+    assert_eq!(
+        sql,
+        "UPDATE bmbp_config_dict SET data_enabled = ?1 WHERE dict_code = ?2"
+    );
+    assert!(matches!(values[0], RdbcValue::Int(1)));
+}
+
+fn join_update_builder() -> RdbcUpdateBuilder {
+    let mut update_builder = RdbcUpdateBuilder::new();
+    update_builder
+        .table_as("bmbp_config_dict", "d")
+        .set("dict_parent_name", "性别");
+    update_builder
+        .join_table_as("bmbp_config_dict", "p")
+        .eq_raw("d.dict_parent_code", "p.dict_code")
+        .eq_v("p.data_status", "Y");
+    update_builder.eq_v("d.dict_code", "sex");
+    update_builder
+}
+
+#[test]
+pub fn test_render_join_update() {
+    let update = join_update_builder().build();
+    let (sql, values) = RdbcUpdateRender::new_with_dialect(update.clone(), RdbcPostgresDialect)
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "UPDATE bmbp_config_dict d SET dict_parent_name = $1 FROM bmbp_config_dict p \
+         WHERE d.dict_parent_code = p.dict_code AND p.data_status = $2 AND d.dict_code = $3"
+    );
+    assert_eq!(values.len(), 3);
+
+    let (sql, _) = RdbcUpdateRender::new_with_dialect(update.clone(), RdbcMysqlDialect::default())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "UPDATE `bmbp_config_dict` `d` INNER JOIN `bmbp_config_dict` `p` \
+         ON `d`.`dict_parent_code` = p.dict_code AND `p`.`data_status` = ? \
+         SET `d`.`dict_parent_name` = ? WHERE `d`.`dict_code` = ?"
+    );
+
+    assert!(
+        RdbcUpdateRender::new_with_dialect(update.clone(), RdbcOracleDialect::default())
+            .render_sql()
+            .is_err()
+    );
+    assert!(
+        RdbcUpdateRender::new_with_dialect(update, RdbcSqliteDialect::new(3_032_000))
+            .render_sql()
+            .is_err()
+    );
+}
+
+#[test]
+pub fn test_render_join_update_qualified_set() {
+    let mut update_builder = join_update_builder();
+    update_builder.set("d.dict_name", "性别");
+    let update = update_builder.build();
+    let (sql, _) = RdbcUpdateRender::new_with_dialect(update.clone(), RdbcPostgresDialect)
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "UPDATE bmbp_config_dict d SET dict_parent_name = $1, dict_name = $2 \
+         FROM bmbp_config_dict p WHERE d.dict_parent_code = p.dict_code \
+         AND p.data_status = $3 AND d.dict_code = $4"
+    );
+    let (sql, _) = RdbcUpdateRender::new_with_dialect(update, RdbcMysqlDialect::default())
+        .render_sql()
+        .unwrap();
+    assert!(sql.contains("SET `d`.`dict_parent_name` = ?, `d`.`dict_name` = ?"));
+
+    let mut update_builder = join_update_builder();
+    update_builder.set("p.dict_name", "性别");
+    assert!(
+        RdbcUpdateRender::new_with_dialect(update_builder.build(), RdbcPostgresDialect)
+            .render_sql()
+            .is_err()
+    );
+}
+
+#[test]
+pub fn test_render_update_limit() {
+    let mut update_builder = RdbcUpdateBuilder::new();
+    update_builder
+        .table("bmbp_config_dict")
+        .set("data_status", "N");
+    update_builder.eq_v("dict_type", "1");
+    update_builder.limit(10);
+    let update = update_builder.build();
+    let (sql, _) = RdbcUpdateRender::new_with_dialect(update.clone(), RdbcMysqlDialect::default())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "UPDATE `bmbp_config_dict` SET `data_status` = ? WHERE `dict_type` = ? LIMIT 10"
+    );
+    assert!(RdbcUpdateRender::new(update).render_sql().is_err());
+
+    let mut join_builder = join_update_builder();
+    join_builder.limit(10);
+    assert!(
+        RdbcUpdateRender::new_with_dialect(join_builder.build(), RdbcMysqlDialect::default())
+            .render_sql()
+            .is_err()
+    );
+}
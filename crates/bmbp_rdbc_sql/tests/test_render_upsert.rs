@@ -0,0 +1,248 @@
+use bmbp_rdbc_sql::dml::RdbcInsertBuilder;
+use bmbp_rdbc_sql::dql::RdbcQueryBuilder;
+use bmbp_rdbc_sql::filter::RdbcWhereFilterBuilder;
+use bmbp_rdbc_sql::select::RdbcSelectBuilder;
+use bmbp_rdbc_sql::table::RdbcTableBuilder;
+use bmbp_rdbc_sql::{
+    RdbcInsertRender, RdbcMysqlDialect, RdbcOracleDialect, RdbcSqlRender, RdbcSqliteDialect,
+};
+
+fn dict_upsert() -> RdbcInsertBuilder {
+    let mut insert_builder = RdbcInsertBuilder::new();
+    insert_builder
+        .insert_table("bmbp_config_dict")
+        .insert_columns(vec!["dict_code", "dict_name", "dict_order"])
+        .insert_values(vec!["sex", "性别", "1"])
+        .on_conflict("dict_code")
+        .do_update_columns(vec!["dict_name", "dict_order"]);
+    insert_builder
+}
+
+#[test]
+pub fn test_render_pg_upsert() {
+    let (sql, values) = RdbcInsertRender::new(dict_upsert().build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "INSERT INTO bmbp_config_dict (dict_code, dict_name, dict_order) VALUES ($1, $2, $3) \
+         ON CONFLICT (dict_code) DO UPDATE SET dict_name = EXCLUDED.dict_name, dict_order = EXCLUDED.dict_order"
+    );
+    assert_eq!(values.len(), 3);
+}
+
+#[test]
+pub fn test_render_pg_upsert_do_nothing() {
+    let mut insert_builder = RdbcInsertBuilder::new();
+    insert_builder
+        .insert_table("bmbp_config_dict")
+        .insert_col_val("dict_code", "sex")
+        .insert_col_val("dict_name", "性别")
+        .do_nothing();
+    let sql = RdbcInsertRender::new(insert_builder.build())
+        .render_raw()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "INSERT INTO bmbp_config_dict (dict_code, dict_name) VALUES ('sex', '性别') ON CONFLICT DO NOTHING"
+    );
+}
+
+#[test]
+pub fn test_render_pg_upsert_constraint_value() {
+    let mut insert_builder = RdbcInsertBuilder::new();
+    insert_builder
+        .insert_table("bmbp_config_dict")
+        .insert_columns(vec!["dict_code", "dict_name"])
+        .insert_values(vec!["sex", "性别"])
+        .on_conflict_constraint("uk_dict_code")
+        .do_update("dict_name")
+        .do_update_value("data_status", "1");
+    let (sql, values) = RdbcInsertRender::new(insert_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "INSERT INTO bmbp_config_dict (dict_code, dict_name) VALUES ($1, $2) \
+         ON CONFLICT ON CONSTRAINT uk_dict_code DO UPDATE SET dict_name = EXCLUDED.dict_name, data_status = $3"
+    );
+    assert_eq!(values.len(), 3);
+}
+
+#[test]
+pub fn test_render_pg_upsert_update_without_target() {
+    let mut insert_builder = RdbcInsertBuilder::new();
+    insert_builder
+        .insert_table("bmbp_config_dict")
+        .insert_col_val("dict_code", "sex")
+        .do_update("dict_code");
+    assert!(RdbcInsertRender::new(insert_builder.build())
+        .render_sql()
+        .is_err());
+}
+
+#[test]
+pub fn test_render_sqlite_upsert() {
+    let (sql, _) = RdbcInsertRender::new_with_dialect(
+        dict_upsert().build(),
+        RdbcSqliteDialect::new(3_039_000),
+    )
+    .render_sql()
+    .unwrap();
+    assert_eq!(
+        sql,
+        "INSERT INTO bmbp_config_dict (dict_code, dict_name, dict_order) VALUES (?1, ?2, ?3) \
+         ON CONFLICT (dict_code) DO UPDATE SET dict_name = EXCLUDED.dict_name, dict_order = EXCLUDED.dict_order"
+    );
+    assert!(RdbcInsertRender::new_with_dialect(
+        dict_upsert().build(),
+        RdbcSqliteDialect::new(3_023_000),
+    )
+    .render_sql()
+    .is_err());
+
+    let mut insert_builder = dict_upsert();
+    insert_builder.on_conflict_constraint("uk_dict_code");
+    assert!(RdbcInsertRender::new_with_dialect(
+        insert_builder.build(),
+        RdbcSqliteDialect::default(),
+    )
+    .render_sql()
+    .is_err());
+}
+
+#[test]
+pub fn test_render_sqlite_upsert_query() {
+    let upsert_query = |query: RdbcQueryBuilder| {
+        let mut insert_builder = RdbcInsertBuilder::new();
+        insert_builder
+            .insert_table("bmbp_config_dict")
+            .insert_columns(vec!["dict_code", "dict_name"])
+            .insert_query(query.build())
+            .on_conflict("dict_code")
+            .do_update_columns(vec!["dict_name"]);
+        let (sql, _) = RdbcInsertRender::new_with_dialect(
+            insert_builder.build(),
+            RdbcSqliteDialect::default(),
+        )
+        .render_sql()
+        .unwrap();
+        sql
+    };
+    let mut query = RdbcQueryBuilder::new();
+    query
+        .table("bmbp_config_dict_tmp")
+        .select("dict_code")
+        .select("dict_name");
+    assert_eq!(
+        upsert_query(query),
+        "INSERT INTO bmbp_config_dict (dict_code, dict_name) \
+         SELECT * FROM (SELECT dict_code, dict_name FROM bmbp_config_dict_tmp) WHERE true \
+         ON CONFLICT (dict_code) DO UPDATE SET dict_name = EXCLUDED.dict_name"
+    );
+
+    let mut query = RdbcQueryBuilder::new();
+    query
+        .table("bmbp_config_dict_tmp")
+        .select("dict_code")
+        .select("dict_name")
+        .eq_v("data_status", "0");
+    assert_eq!(
+        upsert_query(query),
+        "INSERT INTO bmbp_config_dict (dict_code, dict_name) \
+         SELECT dict_code, dict_name FROM bmbp_config_dict_tmp WHERE data_status = ?1 \
+         ON CONFLICT (dict_code) DO UPDATE SET dict_name = EXCLUDED.dict_name"
+    );
+}
+
+#[test]
+pub fn test_render_mysql_upsert() {
+    let (sql, values) =
+        RdbcInsertRender::new_with_dialect(dict_upsert().build(), RdbcMysqlDialect::default())
+            .render_sql()
+            .unwrap();
+    assert_eq!(
+        sql,
+        "INSERT INTO `bmbp_config_dict` (`dict_code`, `dict_name`, `dict_order`) VALUES (?, ?, ?) \
+         ON DUPLICATE KEY UPDATE `dict_name` = VALUES(`dict_name`), `dict_order` = VALUES(`dict_order`)"
+    );
+    assert_eq!(values.len(), 3);
+
+    let mut insert_builder = dict_upsert();
+    insert_builder.do_nothing();
+    let (sql, _) =
+        RdbcInsertRender::new_with_dialect(insert_builder.build(), RdbcMysqlDialect::default())
+            .render_sql()
+            .unwrap();
+    assert_eq!(
+        sql,
+        "INSERT INTO `bmbp_config_dict` (`dict_code`, `dict_name`, `dict_order`) VALUES (?, ?, ?) \
+         ON DUPLICATE KEY UPDATE `dict_code` = `dict_code`"
+    );
+}
+
+#[test]
+pub fn test_render_oracle_merge() {
+    let mut insert_builder = dict_upsert();
+    insert_builder.insert_values(vec!["nation", "民族", "2"]);
+    let (sql, values) =
+        RdbcInsertRender::new_with_dialect(insert_builder.build(), RdbcOracleDialect::default())
+            .render_sql()
+            .unwrap();
+    assert_eq!(
+        sql,
+        "MERGE INTO \"bmbp_config_dict\" t_ USING (\
+         SELECT :1 AS \"dict_code\", :2 AS \"dict_name\", :3 AS \"dict_order\" FROM DUAL UNION ALL \
+         SELECT :4 AS \"dict_code\", :5 AS \"dict_name\", :6 AS \"dict_order\" FROM DUAL) s_ \
+         ON (t_.\"dict_code\" = s_.\"dict_code\") \
+         WHEN MATCHED THEN UPDATE SET t_.\"dict_name\" = s_.\"dict_name\", t_.\"dict_order\" = s_.\"dict_order\" \
+         WHEN NOT MATCHED THEN INSERT (\"dict_code\", \"dict_name\", \"dict_order\") \
+         VALUES (s_.\"dict_code\", s_.\"dict_name\", s_.\"dict_order\")"
+    );
+    assert_eq!(values.len(), 6);
+}
+
+#[test]
+pub fn test_render_oracle_merge_do_nothing() {
+    let mut insert_builder = RdbcInsertBuilder::new();
+    insert_builder
+        .insert_table("bmbp_config_dict")
+        .insert_col_val("dict_code", "sex")
+        .on_conflict("dict_code");
+    let sql =
+        RdbcInsertRender::new_with_dialect(insert_builder.build(), RdbcOracleDialect::default())
+            .render_raw()
+            .unwrap();
+    assert_eq!(
+        sql,
+        "MERGE INTO \"bmbp_config_dict\" t_ USING (SELECT 'sex' AS \"dict_code\" FROM DUAL) s_ \
+         ON (t_.\"dict_code\" = s_.\"dict_code\") \
+         WHEN NOT MATCHED THEN INSERT (\"dict_code\") VALUES (s_.\"dict_code\")"
+    );
+}
+
+#[test]
+pub fn test_render_oracle_merge_invalid() {
+    let mut update_key = dict_upsert();
+    update_key.do_update("dict_code");
+    let mut constraint = dict_upsert();
+    constraint.on_conflict_constraint("uk_dict_code");
+    let mut missing_key = dict_upsert();
+    missing_key.on_conflict("dict_type");
+    let mut query = RdbcQueryBuilder::new();
+    query.table("bmbp_config_dict_tmp").select("dict_code");
+    let mut from_query = RdbcInsertBuilder::new();
+    from_query
+        .insert_table("bmbp_config_dict")
+        .insert_column("dict_code")
+        .insert_query(query.build())
+        .on_conflict("dict_code");
+    for insert_builder in [update_key, constraint, missing_key, from_query] {
+        assert!(RdbcInsertRender::new_with_dialect(
+            insert_builder.build(),
+            RdbcOracleDialect::default(),
+        )
+        .render_sql()
+        .is_err());
+    }
+}
@@ -0,0 +1,87 @@
+use bmbp_rdbc_sql::dql::RdbcQueryBuilder;
+use bmbp_rdbc_sql::select::RdbcSelectBuilder;
+use bmbp_rdbc_sql::table::RdbcTableBuilder;
+use bmbp_rdbc_sql::window::RdbcWindowBuilder;
+use bmbp_rdbc_sql::{
+    RdbcFrameBound, RdbcMysqlDialect, RdbcQueryRender, RdbcSqlRender, RdbcSqliteDialect,
+    RdbcWindowFuncType,
+};
+
+#[test]
+pub fn test_render_window_rank() {
+    let mut window_builder = RdbcWindowBuilder::new();
+    window_builder
+        .partition_by("dict_type")
+        .order_desc("update_time");
+    let window = window_builder.build();
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select("dict_code")
+        .select_row_number_as(window.clone(), "rn")
+        .select_rank_as(window.clone(), "rk")
+        .select_dense_rank_as(window.clone(), "drk")
+        .select_lag_as("dict_code", 1, window.clone(), "prev_code")
+        .select_lead_as("dict_code", 2, window.clone(), "next_code")
+        .select_first_value_as("dict_code", window, "first_code")
+        .table("bmbp_config_dict");
+    let (sql, values) = RdbcQueryRender::new(query_builder.build())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT dict_code, \
+         ROW_NUMBER() OVER (PARTITION BY dict_type ORDER BY update_time DESC) AS rn, \
+         RANK() OVER (PARTITION BY dict_type ORDER BY update_time DESC) AS rk, \
+         DENSE_RANK() OVER (PARTITION BY dict_type ORDER BY update_time DESC) AS drk, \
+         LAG(dict_code, 1) OVER (PARTITION BY dict_type ORDER BY update_time DESC) AS prev_code, \
+         LEAD(dict_code, 2) OVER (PARTITION BY dict_type ORDER BY update_time DESC) AS next_code, \
+         FIRST_VALUE(dict_code) OVER (PARTITION BY dict_type ORDER BY update_time DESC) \
+         AS first_code FROM bmbp_config_dict"
+    );
+    assert!(values.is_empty());
+}
+
+#[test]
+pub fn test_render_window_frame() {
+    let mut window_builder = RdbcWindowBuilder::new();
+    window_builder.order_asc("data_sort").rows_between(
+        RdbcFrameBound::UnboundedPreceding,
+        RdbcFrameBound::CurrentRow,
+    );
+    let running = window_builder.build();
+    let mut window_builder = RdbcWindowBuilder::new();
+    window_builder
+        .partition_by("dict_type")
+        .order_asc("data_sort")
+        .range_between(RdbcFrameBound::Preceding(2), RdbcFrameBound::Following(1));
+    let range = window_builder.build();
+    let mut query_builder = RdbcQueryBuilder::new();
+    query_builder
+        .select_sum_over_as("amount", running.clone(), "total")
+        .select_count_over_as("dict_code", range, "cnt")
+        .select_window_func_as(RdbcWindowFuncType::RowNumber, Default::default(), "rn")
+        .table("bmbp_config_dict");
+    let query = query_builder.build();
+    let (sql, _) = RdbcQueryRender::new_with_dialect(query.clone(), RdbcMysqlDialect::default())
+        .render_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "SELECT SUM(`amount`) OVER (ORDER BY `data_sort` ASC \
+         ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) AS `total`, \
+         COUNT(`dict_code`) OVER (PARTITION BY `dict_type` ORDER BY `data_sort` ASC \
+         RANGE BETWEEN 2 PRECEDING AND 1 FOLLOWING) AS `cnt`, \
+         ROW_NUMBER() OVER () AS `rn` FROM `bmbp_config_dict`"
+    );
+
+    assert!(
+        RdbcQueryRender::new_with_dialect(query.clone(), RdbcSqliteDialect::new(3_024_000))
+            .render_sql()
+            .is_err()
+    );
+    assert!(
+        RdbcQueryRender::new_with_dialect(query, RdbcSqliteDialect::default())
+            .render_sql()
+            .is_ok()
+    );
+}
@@ -7,11 +7,17 @@ pub struct RdbcError {
 #[derive(Debug)]
 pub enum RdbcErrKind {
     CONNECTION,
+    SQL,
 }
 
 impl RdbcError {
     pub fn new(kind: RdbcErrKind, message: String) -> Self {
         Self { kind, message }
     }
+    pub fn kind(&self) -> &RdbcErrKind {
+        &self.kind
+    }
+    pub fn message(&self) -> &str {
+        &self.message
+    }
 }
-
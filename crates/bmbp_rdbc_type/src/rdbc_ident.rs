@@ -1,3 +1,5 @@
+use crate::RdbcValue;
+
 pub trait RdbcIdent {
     fn name(&self) -> String;
 }
@@ -27,28 +29,44 @@ macro_rules! string_ident {
 string_ident!(&str, String, &String);
 
 
+/// 作为 SQL 字面量使用的值，渲染时按方言转义
 pub trait RdbcValueIdent {
-    fn value(&self) -> String;
+    fn value(&self) -> RdbcValue;
 }
 
-macro_rules! select_number_value {
+macro_rules! select_integer_value {
     ( $( $t:ty ),* ) => {
         $(
             impl RdbcValueIdent for $t {
-                fn value(&self) -> String {
-                    self.to_string()
+                fn value(&self) -> RdbcValue {
+                    match i64::try_from(*self) {
+                        Ok(v) => RdbcValue::BigInt(v),
+                        Err(_) => RdbcValue::Decimal(self.to_string()),
+                    }
+                }
+            }
+        )*
+    };
+}
+select_integer_value!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+macro_rules! select_float_value {
+    ( $( $t:ty ),* ) => {
+        $(
+            impl RdbcValueIdent for $t {
+                fn value(&self) -> RdbcValue {
+                    RdbcValue::BigDouble(*self as f64)
                 }
             }
         )*
     };
 }
-select_number_value!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, f32, f64);
+select_float_value!(f32, f64);
 macro_rules! select_string_value {
     ( $( $t:ty ),* ) => {
         $(
             impl RdbcValueIdent for $t {
-                fn value(&self) -> String {
-                    format!("'{}'",self.to_string())
+                fn value(&self) -> RdbcValue {
+                    RdbcValue::Varchar(self.to_string())
                 }
             }
         )*
@@ -76,10 +94,16 @@ pub trait RdbcTableIdent {
         "".to_string()
     }
 }
+/// 类型化的列标识，有表名或表别名时渲染为 alias.column
 pub trait RdbcColumnIdent {
     fn table_name() -> String {
         "".to_string()
     }
+    fn table_alias() -> String {
+        #[allow(deprecated)]
+        Self::table_alis()
+    }
+    #[deprecated(note = "拼写错误，请使用 table_alias")]
     fn table_alis() -> String {
         "".to_string()
     }
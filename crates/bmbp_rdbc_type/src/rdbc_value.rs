@@ -14,6 +14,8 @@ pub enum RdbcValue {
     BigInt(i64),
     Double(f32),
     BigDouble(f64),
+    /// 超出 i64 范围的整数，以数字文本保存
+    Decimal(String),
     Date(chrono::NaiveDate),
     DateTime(chrono::NaiveDateTime),
     Time(chrono::NaiveTime),
@@ -41,7 +43,8 @@ impl RdbcValue {
             | RdbcValue::Int(_)
             | RdbcValue::BigInt(_)
             | RdbcValue::Double(_)
-            | RdbcValue::BigDouble(_) => true,
+            | RdbcValue::BigDouble(_)
+            | RdbcValue::Decimal(_) => true,
             _ => false,
         }
     }
@@ -102,6 +105,7 @@ impl Display for RdbcValue {
             RdbcValue::BigInt(v) => v.to_string(),
             RdbcValue::Double(v) => v.to_string(),
             RdbcValue::BigDouble(v) => v.to_string(),
+            RdbcValue::Decimal(v) => v.to_string(),
             RdbcValue::Date(v) => v.to_string(),
             RdbcValue::DateTime(v) => v.to_string(),
             RdbcValue::Time(v) => v.to_string(),